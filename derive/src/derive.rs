@@ -14,6 +14,12 @@ fn impl_const_size(ty: &Ident, generics: &Generics, fields: &[Field]) -> TokenSt
     TokenStream::from(implementation)
 }
 
+/// The path segment name of a named field (i.e. the field name without a raw identifier prefix)
+fn field_path(field: &Field) -> String {
+    let name = field.ident.as_ref().expect("Named field has no name?!").to_string();
+    name.trim_start_matches("r#").to_string()
+}
+
 /// Implementors for named structs
 pub mod named {
     use super::*;
@@ -21,16 +27,40 @@ pub mod named {
     /// Implements `RawcodeDecode` for `ty` where `ty` is a named struct
     fn impl_decode(ty: &Ident, generics: &Generics, fields: &[Field]) -> TokenStream {
         let fields_name = fields.iter().map(|field| &field.ident);
+        let fields_ty = fields.iter().map(|field| &field.ty);
+        let fields_path: Vec<_> = fields.iter().map(field_path).collect();
+        let ty_name = ty.to_string();
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let implementation = quote! {
             impl #impl_generics ::rawcode::coding::RawcodeDecode for #ty #ty_generics #where_clause {
                 fn decode(buf: &[u8]) -> ::core::result::Result<Self, ::rawcode::error::Error> {
                     // Decode all fields
-                    let mut pos = 0;
-                    let this = Self { #( #fields_name: ::rawcode::coding::from_slice_at(buf, &mut pos)?, )* };
+                    let mut decoder = ::rawcode::coding::Decoder::new(buf);
+                    let this = Self { #(
+                        #fields_name: decoder.get()
+                            .map_err(|error| error.with_field(#fields_path).with_root(#ty_name))?,
+                    )* };
+
+                    // Ensure that all data has been consumed
+                    decoder.finish().map_err(|error| error.with_root(#ty_name))?;
                     Ok(this)
                 }
+
+                fn validate(buf: &[u8], report: &mut dyn ::core::ops::FnMut(::rawcode::error::Error)) {
+                    // Validate all fields
+                    let mut decoder = ::rawcode::coding::Decoder::new(buf);
+                    #(
+                        decoder.validate::<#fields_ty>(&mut |error| {
+                            report(error.with_field(#fields_path).with_root(#ty_name))
+                        });
+                    )*
+
+                    // Ensure that all data has been consumed
+                    if let Err(error) = decoder.finish() {
+                        report(error.with_root(#ty_name));
+                    }
+                }
             }
         };
         TokenStream::from(implementation)
@@ -39,14 +69,22 @@ pub mod named {
     /// Implements `RawcodeEncode` for `ty` where `ty` is a tuple struct
     fn impl_encode(ty: &Ident, generics: &Generics, fields: &[Field]) -> TokenStream {
         let fields_name = fields.iter().map(|field| &field.ident);
+        let fields_path = fields.iter().map(field_path);
+        let ty_name = ty.to_string();
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let implementation = quote! {
             impl #impl_generics ::rawcode::coding::RawcodeEncode for #ty #ty_generics #where_clause {
                 fn encode(&self, buf: &mut [u8]) -> ::core::result::Result<(), ::rawcode::error::Error> {
-                    let mut pos = 0;
-                    #( ::rawcode::coding::to_slice_at(&self.#fields_name, buf, &mut pos)?; )*
-                    Ok(())
+                    // Encode all fields
+                    let mut encoder = ::rawcode::coding::Encoder::new(buf);
+                    #(
+                        encoder.put(&self.#fields_name)
+                            .map_err(|error| error.with_field(#fields_path).with_root(#ty_name))?;
+                    )*
+
+                    // Ensure that the buffer has been filled completely
+                    encoder.finish().map_err(|error| error.with_root(#ty_name))
                 }
             }
         };
@@ -68,17 +106,40 @@ pub mod unnamed {
 
     /// Implements `RawcodeDecode` for `ty` where `ty` is a tuple struct
     fn impl_decode(ty: &Ident, generics: &Generics, fields: &[Field]) -> TokenStream {
-        let fields_ty = fields.iter().map(|field| &field.ty);
+        let fields_ty: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+        let fields_path: Vec<_> = (0..fields.len()).map(|index| index.to_string()).collect();
+        let ty_name = ty.to_string();
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let implementation = quote! {
             impl #impl_generics ::rawcode::coding::RawcodeDecode for #ty #ty_generics #where_clause {
                 fn decode(buf: &[u8]) -> ::core::result::Result<Self, ::rawcode::error::Error> {
                     // Decode all fields
-                    let mut pos = 0;
-                    let this = Self( #( ::rawcode::coding::from_slice_at::<#fields_ty>(buf, &mut pos)?, )* );
+                    let mut decoder = ::rawcode::coding::Decoder::new(buf);
+                    let this = Self( #(
+                        decoder.get::<#fields_ty>()
+                            .map_err(|error| error.with_field(#fields_path).with_root(#ty_name))?,
+                    )* );
+
+                    // Ensure that all data has been consumed
+                    decoder.finish().map_err(|error| error.with_root(#ty_name))?;
                     Ok(this)
                 }
+
+                fn validate(buf: &[u8], report: &mut dyn ::core::ops::FnMut(::rawcode::error::Error)) {
+                    // Validate all fields
+                    let mut decoder = ::rawcode::coding::Decoder::new(buf);
+                    #(
+                        decoder.validate::<#fields_ty>(&mut |error| {
+                            report(error.with_field(#fields_path).with_root(#ty_name))
+                        });
+                    )*
+
+                    // Ensure that all data has been consumed
+                    if let Err(error) = decoder.finish() {
+                        report(error.with_root(#ty_name));
+                    }
+                }
             }
         };
         TokenStream::from(implementation)
@@ -87,14 +148,22 @@ pub mod unnamed {
     /// Implements `RawcodeEncode` for `ty` where `ty` is a tuple struct
     fn impl_encode(ty: &Ident, generics: &Generics, fields: &[Field]) -> TokenStream {
         let fields_index = fields.iter().enumerate().map(|(index, _)| Index::from(index));
+        let fields_path = (0..fields.len()).map(|index| index.to_string());
+        let ty_name = ty.to_string();
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let implementation = quote! {
             impl #impl_generics ::rawcode::coding::RawcodeEncode for #ty #ty_generics #where_clause {
                 fn encode(&self, buf: &mut [u8]) -> ::core::result::Result<(), ::rawcode::error::Error> {
-                    let mut pos = 0;
-                    #( ::rawcode::coding::to_slice_at(&self.#fields_index, buf, &mut pos)?; )*
-                    Ok(())
+                    // Encode all fields
+                    let mut encoder = ::rawcode::coding::Encoder::new(buf);
+                    #(
+                        encoder.put(&self.#fields_index)
+                            .map_err(|error| error.with_field(#fields_path).with_root(#ty_name))?;
+                    )*
+
+                    // Ensure that the buffer has been filled completely
+                    encoder.finish().map_err(|error| error.with_root(#ty_name))
                 }
             }
         };
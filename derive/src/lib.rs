@@ -10,6 +10,11 @@ use syn::{Data, DeriveInput, Field, Fields, Generics, Ident};
 
 /// Implements `rawcode::coding::RawcodeConstSize` and
 /// `rawcode::coding::RawcodeEncode` + `rawcode::coding::RawcodeDecode`
+///
+/// # Length Checks
+/// Since 0.4, the derived implementations require the buffer to be exactly `Self::SIZE` bytes long like the built-in
+/// implementations do: decoding rejects longer inputs with `ErrorKind::TooLong` and encoding rejects larger buffers with
+/// `ErrorKind::BufferTooLarge` instead of ignoring the trailing bytes.
 #[proc_macro_derive(Rawcode)]
 pub fn rawcode_derive(input: TokenStream) -> TokenStream {
     // Parse the input tokens into a syntax tree
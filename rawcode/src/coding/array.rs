@@ -19,8 +19,8 @@ where
     fn decode(buf: &[u8]) -> Result<Self, Error> {
         // Validate the input length
         match buf.len() {
-            len if len > Self::SIZE => return Err(e!("Encoded data is too long")),
-            len if len < Self::SIZE => return Err(e!("Encoded data is too short")),
+            len if len > Self::SIZE => return Err(e!(TooLong { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(TooShort { expected: Self::SIZE, actual: len })),
             _ => (/* all ok */),
         }
 
@@ -28,7 +28,7 @@ where
         let mut elements: [Option<T>; LEN] = [(); LEN].map(|_| None);
         for index in 0..LEN {
             let buf = &buf[index * T::SIZE..][..T::SIZE];
-            let element = T::decode(buf)?;
+            let element = T::decode(buf).map_err(|error| error.with_index(index).with_offset(index * T::SIZE))?;
             elements[index] = Some(element);
         }
 
@@ -36,6 +36,21 @@ where
         let elements = elements.map(|element| element.expect("Array element is not initialized?!"));
         Ok(elements)
     }
+
+    fn validate(buf: &[u8], report: &mut dyn FnMut(Error)) {
+        // Validate the input length
+        match buf.len() {
+            len if len > Self::SIZE => return report(e!(TooLong { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return report(e!(TooShort { expected: Self::SIZE, actual: len })),
+            _ => (/* all ok */),
+        }
+
+        // Validate all elements
+        for index in 0..LEN {
+            let buf = &buf[index * T::SIZE..][..T::SIZE];
+            T::validate(buf, &mut |error| report(error.with_index(index).with_offset(index * T::SIZE)));
+        }
+    }
 }
 impl<const LEN: usize, T> RawcodeEncode for [T; LEN]
 where
@@ -44,15 +59,15 @@ where
     fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
         // Validate the buffer length
         match buf.len() {
-            len if len > Self::SIZE => return Err(e!("Target buffer is too large")),
-            len if len < Self::SIZE => return Err(e!("Target buffer is too small")),
+            len if len > Self::SIZE => return Err(e!(BufferTooLarge { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(BufferTooSmall { expected: Self::SIZE, actual: len })),
             _ => (/* all ok */),
         }
 
         // Encode all elements
         for (index, element) in self.iter().enumerate() {
             let buf = &mut buf[index * T::SIZE..][..T::SIZE];
-            element.encode(buf)?;
+            element.encode(buf).map_err(|error| error.with_index(index).with_offset(index * T::SIZE))?;
         }
         Ok(())
     }
@@ -0,0 +1,156 @@
+//! Implements the encoding of ASCII arrays
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+#[cfg(feature = "alloc")]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Debug, Formatter},
+    ops::Deref,
+    str,
+};
+
+/// An ASCII array that always guarantees that it's contents are 7-bit ASCII (and optionally printable, i.e. within
+/// `0x20..=0x7E`)
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiArray<const LEN: usize, const PRINTABLE: bool = false> {
+    /// The underlying bytes
+    ascii_bytes: [u8; LEN],
+}
+impl<const LEN: usize, const PRINTABLE: bool> AsciiArray<LEN, PRINTABLE> {
+    /// Creates a new `AsciiArray`
+    ///
+    /// # Panic
+    /// Panics if the literal contains invalid bytes
+    pub const fn new(bytes: &[u8; LEN]) -> Self {
+        match Self::from_array(*bytes) {
+            Some(this) => this,
+            None => panic!("Invalid string literal"),
+        }
+    }
+
+    /// Tries to create a new `AsciiArray` from a byte array
+    const fn from_array(array: [u8; LEN]) -> Option<Self> {
+        let mut index = 0;
+        while index < LEN {
+            match array[index] {
+                0x20..=0x7e => (/* printable ASCII */),
+                0x00..=0x7f if !PRINTABLE => (/* non-printable ASCII */),
+                _ => return None,
+            }
+            index += 1;
+        }
+        Some(Self { ascii_bytes: array })
+    }
+
+    /// The underlying bytes as string slice
+    pub fn as_str(&self) -> &str {
+        // This is safe because ASCII is always valid UTF-8 and we've already validated the string during construction
+        unsafe { str::from_utf8_unchecked(&self.ascii_bytes) }
+    }
+}
+impl<const LEN: usize, const PRINTABLE: bool> Debug for AsciiArray<LEN, PRINTABLE> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("AsciiArray").field("ascii_bytes", &self.as_str()).finish()
+    }
+}
+impl<const LEN: usize, const PRINTABLE: bool> TryFrom<&str> for AsciiArray<LEN, PRINTABLE> {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let bytes = <[u8; LEN]>::try_from(value.as_bytes()).map_err(|_| e!(InvalidLength, "Invalid string length"))?;
+        Self::try_from(bytes)
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const LEN: usize, const PRINTABLE: bool> TryFrom<String> for AsciiArray<LEN, PRINTABLE> {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+impl<const LEN: usize, const PRINTABLE: bool> TryFrom<[u8; LEN]> for AsciiArray<LEN, PRINTABLE> {
+    type Error = Error;
+
+    fn try_from(bytes: [u8; LEN]) -> Result<Self, Self::Error> {
+        Self::from_array(bytes).ok_or(e!(InvalidText, "Array contains invalid ASCII bytes"))
+    }
+}
+impl<const LEN: usize, const PRINTABLE: bool> TryFrom<&[u8]> for AsciiArray<LEN, PRINTABLE> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = <[u8; LEN]>::try_from(value).map_err(|_| e!(InvalidLength, "Invalid slice length"))?;
+        Self::try_from(bytes)
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const LEN: usize, const PRINTABLE: bool> TryFrom<Vec<u8>> for AsciiArray<LEN, PRINTABLE> {
+    type Error = Error;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
+    }
+}
+impl<const LEN: usize, const PRINTABLE: bool> Deref for AsciiArray<LEN, PRINTABLE> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+impl<const LEN: usize, const PRINTABLE: bool> AsRef<str> for AsciiArray<LEN, PRINTABLE> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl<const LEN: usize, const PRINTABLE: bool> AsRef<[u8]> for AsciiArray<LEN, PRINTABLE> {
+    fn as_ref(&self) -> &[u8] {
+        &self.ascii_bytes
+    }
+}
+impl<const LEN: usize, const PRINTABLE: bool> AsRef<[u8; LEN]> for AsciiArray<LEN, PRINTABLE> {
+    fn as_ref(&self) -> &[u8; LEN] {
+        &self.ascii_bytes
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const LEN: usize, const PRINTABLE: bool> From<AsciiArray<LEN, PRINTABLE>> for String {
+    fn from(array: AsciiArray<LEN, PRINTABLE>) -> Self {
+        array.as_str().to_string()
+    }
+}
+impl<const LEN: usize, const PRINTABLE: bool> From<AsciiArray<LEN, PRINTABLE>> for [u8; LEN] {
+    fn from(array: AsciiArray<LEN, PRINTABLE>) -> Self {
+        array.ascii_bytes
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const LEN: usize, const PRINTABLE: bool> From<AsciiArray<LEN, PRINTABLE>> for Vec<u8> {
+    fn from(array: AsciiArray<LEN, PRINTABLE>) -> Self {
+        array.ascii_bytes.to_vec()
+    }
+}
+impl<const LEN: usize, const PRINTABLE: bool> RawcodeConstSize for AsciiArray<LEN, PRINTABLE> {
+    const SIZE: usize = <[u8; LEN]>::SIZE;
+}
+impl<const LEN: usize, const PRINTABLE: bool> RawcodeEncode for AsciiArray<LEN, PRINTABLE> {
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        self.ascii_bytes.encode(buf)
+    }
+}
+impl<const LEN: usize, const PRINTABLE: bool> RawcodeDecode for AsciiArray<LEN, PRINTABLE> {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        let bytes = <[u8; LEN]>::decode(buf)?;
+        Self::try_from(bytes)
+    }
+}
+
+/// An ASCII array that always guarantees that it's contents are printable 7-bit ASCII (i.e. within `0x20..=0x7E`)
+pub type PrintableAsciiArray<const LEN: usize> = AsciiArray<LEN, true>;
@@ -0,0 +1,99 @@
+//! Implements packed binary-coded decimal (BCD) coding
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+
+/// An unsigned integer `T` with at most `DIGITS` decimal digits that is encoded as packed BCD (e.g. `Bcd<u32, 8>`)
+///
+/// # Encoding
+/// Each byte holds two decimal digits where the high nibble is the more significant digit; the digit pairs are stored
+/// least-significant pair first (i.e. **little-endian**), and the value occupies `DIGITS.div_ceil(2)` bytes. During
+/// decoding, nibbles greater than `9` are rejected, and if `DIGITS` is odd, the unused most-significant nibble must be
+/// zero.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bcd<T, const DIGITS: usize> {
+    /// The underlying value
+    value: T,
+}
+impl<T, const DIGITS: usize> Bcd<T, DIGITS>
+where
+    T: Copy + Into<u64>,
+{
+    /// The amount of decimal digits
+    pub const DIGITS: usize = {
+        assert!(DIGITS > 0 && DIGITS < 20, "Invalid amount of digits");
+        DIGITS
+    };
+    /// The largest representable value (i.e. `10^DIGITS - 1`)
+    pub const MAX: u64 = 10u64.pow(Self::DIGITS as u32) - 1;
+
+    /// Creates a new BCD integer if `value` has at most `DIGITS` decimal digits
+    pub fn new(value: T) -> Result<Self, Error> {
+        match value.into() {
+            wide if wide > Self::MAX => Err(e!(OutOfRange, "Value has too many decimal digits")),
+            _ => Ok(Self { value }),
+        }
+    }
+
+    /// The underlying value
+    pub const fn get(self) -> T {
+        self.value
+    }
+}
+impl<T, const DIGITS: usize> RawcodeConstSize for Bcd<T, DIGITS>
+where
+    T: Copy + Into<u64>,
+{
+    const SIZE: usize = Self::DIGITS.div_ceil(2);
+}
+impl<T, const DIGITS: usize> RawcodeDecode for Bcd<T, DIGITS>
+where
+    T: Copy + Into<u64> + TryFrom<u64>,
+{
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        // Validate the input length
+        match buf.len() {
+            len if len > Self::SIZE => return Err(e!(TooLong { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(TooShort { expected: Self::SIZE, actual: len })),
+            _ => (/* all ok */),
+        }
+
+        // Decode the digit pairs starting with the most-significant pair
+        let mut value = 0u64;
+        for (index, byte) in buf.iter().enumerate().rev() {
+            let (high, low) = (u64::from(byte >> 4), u64::from(byte & 0x0f));
+            match (high, low) {
+                (10.., _) | (_, 10..) => return Err(e!(Validation, "Invalid BCD digit")),
+                (1.., _) if 2 * index + 1 >= DIGITS => return Err(e!(InvalidPadding, "Unused BCD digit is not zero")),
+                _ => value = (value * 100) + (high * 10) + low,
+            }
+        }
+
+        // Convert the value
+        let value = T::try_from(value).map_err(|_| e!(OutOfRange, "Value does not fit into target type"))?;
+        Ok(Self { value })
+    }
+}
+impl<T, const DIGITS: usize> RawcodeEncode for Bcd<T, DIGITS>
+where
+    T: Copy + Into<u64>,
+{
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        // Validate the buffer length
+        match buf.len() {
+            len if len > Self::SIZE => return Err(e!(BufferTooLarge { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(BufferTooSmall { expected: Self::SIZE, actual: len })),
+            _ => (/* all ok */),
+        }
+
+        // Encode the digit pairs starting with the least-significant pair
+        let mut value: u64 = self.value.into();
+        for byte in buf.iter_mut() {
+            *byte = (((value / 10) % 10) << 4 | (value % 10)) as u8;
+            value /= 100;
+        }
+        Ok(())
+    }
+}
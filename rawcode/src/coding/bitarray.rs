@@ -0,0 +1,116 @@
+//! Implements the encoding of bit arrays
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+use core::fmt::{self, Debug, Formatter};
+
+/// An array of `BITS` bits
+///
+/// # Encoding
+/// The bits are packed into `ceil(BITS / 8)` bytes where bit `n` is stored in byte `n / 8` at bit position `n % 8`
+/// (i.e. a `BitArray<32>` is encoded like a little-endian `u32`). The unused high bits of the last byte are zero during
+/// encoding; during decoding, non-zero unused bits are rejected.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitArray<const BITS: usize> {
+    /// The bits
+    bits: [bool; BITS],
+}
+impl<const BITS: usize> BitArray<BITS> {
+    /// Creates a new bit array with all bits cleared
+    pub const fn new() -> Self {
+        Self { bits: [false; BITS] }
+    }
+
+    /// Tests the bit at `index`
+    ///
+    /// # Panic
+    /// Panics if `index` is out of range
+    pub const fn test(&self, index: usize) -> bool {
+        self.bits[index]
+    }
+    /// Sets the bit at `index` to `value`
+    ///
+    /// # Panic
+    /// Panics if `index` is out of range
+    pub fn set(&mut self, index: usize, value: bool) {
+        self.bits[index] = value;
+    }
+
+    /// The amount of set bits
+    pub fn count_ones(&self) -> usize {
+        self.bits.iter().filter(|bit| **bit).count()
+    }
+    /// An iterator over all bits
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        self.bits.iter().copied()
+    }
+    /// An iterator over the indices of all set bits
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.bits.iter().enumerate().filter(|(_, bit)| **bit).map(|(index, _)| index)
+    }
+}
+impl<const BITS: usize> Default for BitArray<BITS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<const BITS: usize> Debug for BitArray<BITS> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("BitArray(0b")?;
+        self.bits.iter().rev().try_for_each(|bit| f.write_str(if *bit { "1" } else { "0" }))?;
+        f.write_str(")")
+    }
+}
+impl<const BITS: usize> From<[bool; BITS]> for BitArray<BITS> {
+    fn from(bits: [bool; BITS]) -> Self {
+        Self { bits }
+    }
+}
+impl<const BITS: usize> From<BitArray<BITS>> for [bool; BITS] {
+    fn from(array: BitArray<BITS>) -> Self {
+        array.bits
+    }
+}
+impl<const BITS: usize> RawcodeConstSize for BitArray<BITS> {
+    const SIZE: usize = BITS.div_ceil(8);
+}
+impl<const BITS: usize> RawcodeDecode for BitArray<BITS> {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        // Validate the input length
+        match buf.len() {
+            len if len > Self::SIZE => return Err(e!(TooLong { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(TooShort { expected: Self::SIZE, actual: len })),
+            _ => (/* all ok */),
+        }
+
+        // Unpack the bits and validate that the unused bits are zero
+        let mut bits = [false; BITS];
+        for index in 0..Self::SIZE * 8 {
+            let bit = buf[index / 8] & (1 << (index % 8)) != 0;
+            match bits.get_mut(index) {
+                Some(slot) => *slot = bit,
+                None if bit => return Err(e!(InvalidPadding, "Unused bits are not zero")),
+                None => (/* all ok */),
+            }
+        }
+        Ok(Self { bits })
+    }
+}
+impl<const BITS: usize> RawcodeEncode for BitArray<BITS> {
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        // Validate the buffer length
+        match buf.len() {
+            len if len > Self::SIZE => return Err(e!(BufferTooLarge { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(BufferTooSmall { expected: Self::SIZE, actual: len })),
+            _ => buf.fill(0),
+        }
+
+        // Pack the bits
+        for index in self.ones() {
+            buf[index / 8] |= 1 << (index % 8);
+        }
+        Ok(())
+    }
+}
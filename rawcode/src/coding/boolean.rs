@@ -14,7 +14,7 @@ impl RawcodeDecode for bool {
         match value {
             0x00 => Ok(false),
             0xff => Ok(true),
-            _ => Err(e!("Invalid boolean")),
+            _ => Err(e!(InvalidBool, "Invalid boolean")),
         }
     }
 }
@@ -0,0 +1,198 @@
+//! Implements the encoding of bounded vectors
+
+use crate::{
+    coding::{size::UsizeAs, RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+
+/// An array-backed vector with a fixed capacity of `CAP` elements
+///
+/// # Encoding
+/// The vector is encoded as the element count (encoded as `UsizeAs<L>`), followed by `CAP` element slots. Unused slots
+/// are zero-filled during encoding; during decoding, element counts that exceed `CAP` or unused slots that are not
+/// zero-filled are rejected (i.e. decoding is always strict and unused slots cannot carry arbitrary data).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedVec<T, const CAP: usize, L = u32> {
+    /// The element slots where the first `len` slots are occupied
+    elements: [Option<T>; CAP],
+    /// The amount of elements
+    len: usize,
+    /// The length prefix type
+    _length: PhantomData<L>,
+}
+impl<T, const CAP: usize, L> BoundedVec<T, CAP, L> {
+    /// Creates a new empty vector
+    pub fn new() -> Self {
+        Self { elements: [(); CAP].map(|_| None), len: 0, _length: PhantomData }
+    }
+
+    /// The amount of elements
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// Whether the vector is empty or not
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// The maximum amount of elements
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Appends an element to the end of the vector
+    pub fn push(&mut self, element: T) -> Result<(), Error> {
+        let slot = self.elements.get_mut(self.len).ok_or(e!(OutOfRange, "Vector is full"))?;
+        *slot = Some(element);
+        self.len += 1;
+        Ok(())
+    }
+    /// Removes the last element from the vector
+    pub fn pop(&mut self) -> Option<T> {
+        let index = self.len.checked_sub(1)?;
+        self.len = index;
+        self.elements[index].take()
+    }
+    /// Removes all elements from the vector
+    pub fn clear(&mut self) {
+        self.elements.iter_mut().for_each(|slot| *slot = None);
+        self.len = 0;
+    }
+
+    /// Gets a reference to the element at `index`
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.elements.get(index)?.as_ref()
+    }
+    /// Gets a mutable reference to the element at `index`
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.elements.get_mut(index)?.as_mut()
+    }
+    /// An iterator over the elements
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.elements[..self.len].iter().flatten()
+    }
+    /// A mutable iterator over the elements
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.elements[..self.len].iter_mut().flatten()
+    }
+}
+impl<T, const CAP: usize, L> Default for BoundedVec<T, CAP, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, const CAP: usize, L> Debug for BoundedVec<T, CAP, L>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl<T, const CAP: usize, L> TryFrom<&[T]> for BoundedVec<T, CAP, L>
+where
+    T: Clone,
+{
+    type Error = Error;
+
+    fn try_from(value: &[T]) -> Result<Self, Self::Error> {
+        let mut this = Self::new();
+        for element in value {
+            this.push(element.clone()).map_err(|_| e!(InvalidLength, "Invalid slice length"))?;
+        }
+        Ok(this)
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T, const CAP: usize, L> TryFrom<Vec<T>> for BoundedVec<T, CAP, L> {
+    type Error = Error;
+
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        let mut this = Self::new();
+        for element in value {
+            this.push(element).map_err(|_| e!(InvalidLength, "Invalid vector length"))?;
+        }
+        Ok(this)
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T, const CAP: usize, L> From<BoundedVec<T, CAP, L>> for Vec<T> {
+    fn from(vec: BoundedVec<T, CAP, L>) -> Self {
+        vec.elements.into_iter().flatten().collect()
+    }
+}
+impl<T, const CAP: usize, L> RawcodeConstSize for BoundedVec<T, CAP, L>
+where
+    T: RawcodeConstSize,
+    L: RawcodeConstSize,
+{
+    const SIZE: usize = UsizeAs::<L>::SIZE + <[T; CAP]>::SIZE;
+}
+impl<T, const CAP: usize, L> RawcodeDecode for BoundedVec<T, CAP, L>
+where
+    T: RawcodeDecode,
+    L: RawcodeDecode,
+    usize: TryFrom<L>,
+{
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        // Validate the input length
+        match buf.len() {
+            len if len > Self::SIZE => return Err(e!(TooLong { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(TooShort { expected: Self::SIZE, actual: len })),
+            _ => (/* all ok */),
+        }
+
+        // Decode and validate the element count
+        let (len, slots) = buf.split_at(UsizeAs::<L>::SIZE);
+        let len = UsizeAs::<L>::decode(len)?.get();
+        if len > CAP {
+            return Err(e!(OutOfRange, "Element count exceeds capacity"));
+        }
+
+        // Decode all elements and validate that the unused slots are zero-filled
+        let mut elements: [Option<T>; CAP] = [(); CAP].map(|_| None);
+        for (index, slot) in elements.iter_mut().enumerate() {
+            let (buf, offset) = (&slots[index * T::SIZE..][..T::SIZE], UsizeAs::<L>::SIZE + index * T::SIZE);
+            let result = match index < len {
+                true => T::decode(buf).map(|element| *slot = Some(element)),
+                false if buf.iter().any(|byte| *byte != 0) => Err(e!(InvalidPadding, "Unused slot is not zero-filled")),
+                false => Ok(()),
+            };
+            result.map_err(|error| error.with_index(index).with_offset(offset))?;
+        }
+        Ok(Self { elements, len, _length: PhantomData })
+    }
+}
+impl<T, const CAP: usize, L> RawcodeEncode for BoundedVec<T, CAP, L>
+where
+    T: RawcodeEncode,
+    L: RawcodeEncode + TryFrom<usize>,
+{
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        // Validate the buffer length
+        match buf.len() {
+            len if len > Self::SIZE => return Err(e!(BufferTooLarge { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(BufferTooSmall { expected: Self::SIZE, actual: len })),
+            _ => (/* all ok */),
+        }
+
+        // Encode the element count
+        let (len, slots) = buf.split_at_mut(UsizeAs::<L>::SIZE);
+        UsizeAs::<L>::new(self.len).encode(len)?;
+
+        // Encode all elements and zero-fill the unused slots
+        for (index, slot) in self.elements.iter().enumerate() {
+            let (buf, offset) = (&mut slots[index * T::SIZE..][..T::SIZE], UsizeAs::<L>::SIZE + index * T::SIZE);
+            match slot {
+                Some(element) => element.encode(buf).map_err(|error| error.with_index(index).with_offset(offset))?,
+                None => buf.fill(0),
+            }
+        }
+        Ok(())
+    }
+}
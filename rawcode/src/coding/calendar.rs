@@ -0,0 +1,288 @@
+//! Implements the encoding of calendar dates and times
+
+use crate::{
+    coding::{Decoder, Encoder, RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+use core::fmt::{self, Display, Formatter};
+
+/// A calendar date in the proleptic Gregorian calendar
+///
+/// # Encoding
+/// The date is encoded as year (`u16`), month (`u8`, `1..=12`) and day (`u8`, `1..=31` depending on the month and leap
+/// year); invalid dates are rejected during decoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    /// The year
+    year: u16,
+    /// The month within `1..=12`
+    month: u8,
+    /// The day within `1..=31`
+    day: u8,
+}
+impl Date {
+    /// Creates a new date if the fields are valid
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, Error> {
+        match (month, day) {
+            (1..=12, 1..=31) if day <= Self::days_in_month(year, month) => Ok(Self { year, month, day }),
+            (1..=12, _) => Err(e!(Validation, "Invalid day of month")),
+            _ => Err(e!(Validation, "Invalid month")),
+        }
+    }
+
+    /// The year
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+    /// The month within `1..=12`
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+    /// The day within `1..=31`
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Whether `year` is a leap year or not
+    pub const fn is_leap_year(year: u16) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+    /// The amount of days of `month` in `year` (or `0` if the month is invalid)
+    pub const fn days_in_month(year: u16, month: u8) -> u8 {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            _ => 0,
+        }
+    }
+}
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+impl RawcodeConstSize for Date {
+    const SIZE: usize = u16::SIZE + u8::SIZE + u8::SIZE;
+}
+impl RawcodeDecode for Date {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        // Validate the input length
+        match buf.len() {
+            len if len > Self::SIZE => return Err(e!(TooLong { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(TooShort { expected: Self::SIZE, actual: len })),
+            _ => (/* all ok */),
+        }
+
+        // Decode and validate the fields
+        let mut decoder = Decoder::new(buf);
+        let year = decoder.get()?;
+        let month = decoder.get()?;
+        let day = decoder.get()?;
+        Self::new(year, month, day)
+    }
+}
+impl RawcodeEncode for Date {
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        // Validate the buffer length
+        match buf.len() {
+            len if len > Self::SIZE => return Err(e!(BufferTooLarge { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(BufferTooSmall { expected: Self::SIZE, actual: len })),
+            _ => (/* all ok */),
+        }
+
+        // Encode the fields
+        let mut encoder = Encoder::new(buf);
+        encoder.put(&self.year)?;
+        encoder.put(&self.month)?;
+        encoder.put(&self.day)
+    }
+}
+
+/// A time of day
+///
+/// # Encoding
+/// The time is encoded as hour (`u8`, `0..=23`), minute (`u8`, `0..=59`) and second (`u8`, `0..=59`); invalid times are
+/// rejected during decoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeOfDay {
+    /// The hour within `0..=23`
+    hour: u8,
+    /// The minute within `0..=59`
+    minute: u8,
+    /// The second within `0..=59`
+    second: u8,
+}
+impl TimeOfDay {
+    /// Creates a new time of day if the fields are valid
+    pub fn new(hour: u8, minute: u8, second: u8) -> Result<Self, Error> {
+        match (hour, minute, second) {
+            (0..=23, 0..=59, 0..=59) => Ok(Self { hour, minute, second }),
+            (0..=23, 0..=59, _) => Err(e!(Validation, "Invalid second")),
+            (0..=23, _, _) => Err(e!(Validation, "Invalid minute")),
+            _ => Err(e!(Validation, "Invalid hour")),
+        }
+    }
+
+    /// The hour within `0..=23`
+    pub const fn hour(&self) -> u8 {
+        self.hour
+    }
+    /// The minute within `0..=59`
+    pub const fn minute(&self) -> u8 {
+        self.minute
+    }
+    /// The second within `0..=59`
+    pub const fn second(&self) -> u8 {
+        self.second
+    }
+}
+impl Display for TimeOfDay {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+impl RawcodeConstSize for TimeOfDay {
+    const SIZE: usize = u8::SIZE + u8::SIZE + u8::SIZE;
+}
+impl RawcodeDecode for TimeOfDay {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        let [hour, minute, second] = <[u8; 3]>::decode(buf)?;
+        Self::new(hour, minute, second)
+    }
+}
+impl RawcodeEncode for TimeOfDay {
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        [self.hour, self.minute, self.second].encode(buf)
+    }
+}
+
+/// A calendar date and time of day
+///
+/// # Encoding
+/// The date time is encoded as `Date` followed by `TimeOfDay`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    /// The date
+    date: Date,
+    /// The time of day
+    time: TimeOfDay,
+}
+impl DateTime {
+    /// Creates a new date time
+    pub const fn new(date: Date, time: TimeOfDay) -> Self {
+        Self { date, time }
+    }
+
+    /// The date
+    pub const fn date(&self) -> Date {
+        self.date
+    }
+    /// The time of day
+    pub const fn time(&self) -> TimeOfDay {
+        self.time
+    }
+}
+impl Display for DateTime {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
+impl RawcodeConstSize for DateTime {
+    const SIZE: usize = Date::SIZE + TimeOfDay::SIZE;
+}
+impl RawcodeDecode for DateTime {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        // Validate the input length
+        match buf.len() {
+            len if len > Self::SIZE => return Err(e!(TooLong { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(TooShort { expected: Self::SIZE, actual: len })),
+            _ => (/* all ok */),
+        }
+
+        // Decode the fields
+        let mut decoder = Decoder::new(buf);
+        let date = decoder.get()?;
+        let time = decoder.get()?;
+        Ok(Self { date, time })
+    }
+}
+impl RawcodeEncode for DateTime {
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        // Validate the buffer length
+        match buf.len() {
+            len if len > Self::SIZE => return Err(e!(BufferTooLarge { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(BufferTooSmall { expected: Self::SIZE, actual: len })),
+            _ => (/* all ok */),
+        }
+
+        // Encode the fields
+        let mut encoder = Encoder::new(buf);
+        encoder.put(&self.date)?;
+        encoder.put(&self.time)
+    }
+}
+
+/// A packed MS-DOS date and time (as used by e.g. FAT and ZIP)
+///
+/// # Encoding
+/// The date time is encoded as packed time (`u16`) followed by packed date (`u16`):
+/// - time: bits 15-11 are the hour, bits 10-5 are the minute, bits 4-0 are the second divided by two
+/// - date: bits 15-9 are the year since 1980, bits 8-5 are the month, bits 4-0 are the day
+///
+/// Therefore only years within `1980..=2107` and even seconds can be represented; invalid dates and times are rejected
+/// during decoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DosDateTime {
+    /// The date time
+    date_time: DateTime,
+}
+impl DosDateTime {
+    /// The first representable year
+    const EPOCH_YEAR: u16 = 1980;
+
+    /// The date time
+    pub const fn date_time(&self) -> DateTime {
+        self.date_time
+    }
+}
+impl TryFrom<DateTime> for DosDateTime {
+    type Error = Error;
+
+    fn try_from(date_time: DateTime) -> Result<Self, Self::Error> {
+        match (date_time.date.year, date_time.time.second) {
+            (year, _) if !(Self::EPOCH_YEAR..=Self::EPOCH_YEAR + 127).contains(&year) => {
+                Err(e!(OutOfRange, "Year is not representable as MS-DOS date"))
+            }
+            (_, second) if second % 2 != 0 => Err(e!(Validation, "Odd seconds are not representable as MS-DOS time")),
+            _ => Ok(Self { date_time }),
+        }
+    }
+}
+impl From<DosDateTime> for DateTime {
+    fn from(dos: DosDateTime) -> Self {
+        dos.date_time
+    }
+}
+impl RawcodeConstSize for DosDateTime {
+    const SIZE: usize = u16::SIZE + u16::SIZE;
+}
+impl RawcodeDecode for DosDateTime {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        // Decode the packed fields
+        let [time, date] = <[u16; 2]>::decode(buf)?;
+        let time = TimeOfDay::new((time >> 11) as u8, ((time >> 5) & 0x3f) as u8, ((time & 0x1f) * 2) as u8)?;
+        let date = Date::new((date >> 9) + Self::EPOCH_YEAR, ((date >> 5) & 0x0f) as u8, (date & 0x1f) as u8)?;
+        Ok(Self { date_time: DateTime::new(date, time) })
+    }
+}
+impl RawcodeEncode for DosDateTime {
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        // Pack the fields
+        let (date, time) = (self.date_time.date, self.date_time.time);
+        let packed_time = (u16::from(time.hour) << 11) | (u16::from(time.minute) << 5) | u16::from(time.second / 2);
+        let packed_date = ((date.year - Self::EPOCH_YEAR) << 9) | (u16::from(date.month) << 5) | u16::from(date.day);
+        [packed_time, packed_date].encode(buf)
+    }
+}
@@ -0,0 +1,178 @@
+//! Implements the encoding of NUL-terminated C strings
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, ffi::CString, string::String};
+use core::{
+    ffi::CStr,
+    fmt::{self, Debug, Formatter},
+    ops::Deref,
+    str,
+};
+
+/// A NUL-terminated C string within a `LEN`-byte buffer (e.g. `char name[32]`)
+///
+/// # Encoding
+/// The buffer must contain at least one NUL byte which terminates the string. During encoding, all bytes after the
+/// terminator are zeroed; during decoding, all bytes after the terminator are ignored.
+///
+/// # Note
+/// `LEN` must be at least `1` to leave space for the terminator; this is checked at compile time.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CStrArray<const LEN: usize> {
+    /// The underlying bytes, zero-filled after the terminator
+    bytes: [u8; LEN],
+}
+impl<const LEN: usize> CStrArray<LEN> {
+    /// The buffer length
+    pub const LEN: usize = {
+        assert!(LEN > 0, "Buffer has no space for the terminator");
+        LEN
+    };
+
+    /// Creates a new `CStrArray`
+    ///
+    /// # Panic
+    /// Panics if the literal does not fit into the buffer
+    pub const fn new(string: &CStr) -> Self {
+        match Self::from_content(string.to_bytes()) {
+            Some(this) => this,
+            None => panic!("Invalid string literal"),
+        }
+    }
+
+    /// Tries to create a new `CStrArray` from the given content bytes
+    const fn from_content(content: &[u8]) -> Option<Self> {
+        // Validate the content length to ensure there is space for the terminator
+        if content.len() >= Self::LEN {
+            return None;
+        }
+
+        // Copy the content and validate that it does not contain NUL bytes
+        let mut bytes = [0; LEN];
+        let mut index = 0;
+        while index < content.len() {
+            match content[index] {
+                0x00 => return None,
+                byte => bytes[index] = byte,
+            }
+            index += 1;
+        }
+        Some(Self { bytes })
+    }
+
+    /// The content as C string
+    pub fn as_c_str(&self) -> &CStr {
+        // The buffer is guaranteed to contain a NUL byte since it has been validated during construction
+        CStr::from_bytes_until_nul(&self.bytes).expect("Array is not NUL-terminated?!")
+    }
+    /// The content bytes without the terminator
+    pub fn as_bytes(&self) -> &[u8] {
+        self.as_c_str().to_bytes()
+    }
+    /// The content as string slice if it is valid UTF-8
+    pub fn to_str(&self) -> Result<&str, Error> {
+        str::from_utf8(self.as_bytes()).map_err(|_| e!(InvalidUtf8, "Array contains non-UTF-8 bytes"))
+    }
+}
+impl<const LEN: usize> Default for CStrArray<LEN> {
+    fn default() -> Self {
+        Self::new(c"")
+    }
+}
+impl<const LEN: usize> Debug for CStrArray<LEN> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("CStrArray").field("bytes", &self.as_c_str()).finish()
+    }
+}
+impl<const LEN: usize> TryFrom<&CStr> for CStrArray<LEN> {
+    type Error = Error;
+
+    fn try_from(value: &CStr) -> Result<Self, Self::Error> {
+        Self::from_content(value.to_bytes()).ok_or(e!(InvalidLength, "Invalid string length"))
+    }
+}
+impl<const LEN: usize> TryFrom<&str> for CStrArray<LEN> {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_content(value.as_bytes()).ok_or(e!(InvalidLength, "Invalid string length or content"))
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const LEN: usize> TryFrom<String> for CStrArray<LEN> {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const LEN: usize> TryFrom<CString> for CStrArray<LEN> {
+    type Error = Error;
+
+    fn try_from(value: CString) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_c_str())
+    }
+}
+impl<const LEN: usize> TryFrom<[u8; LEN]> for CStrArray<LEN> {
+    type Error = Error;
+
+    fn try_from(bytes: [u8; LEN]) -> Result<Self, Self::Error> {
+        let string = CStr::from_bytes_until_nul(&bytes).map_err(|_| e!(InvalidText, "Array is not NUL-terminated"))?;
+        Self::try_from(string)
+    }
+}
+impl<const LEN: usize> TryFrom<&[u8]> for CStrArray<LEN> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = <[u8; LEN]>::try_from(value).map_err(|_| e!(InvalidLength, "Invalid slice length"))?;
+        Self::try_from(bytes)
+    }
+}
+impl<const LEN: usize> Deref for CStrArray<LEN> {
+    type Target = CStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_c_str()
+    }
+}
+impl<const LEN: usize> AsRef<CStr> for CStrArray<LEN> {
+    fn as_ref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+impl<const LEN: usize> AsRef<[u8]> for CStrArray<LEN> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const LEN: usize> From<CStrArray<LEN>> for CString {
+    fn from(array: CStrArray<LEN>) -> Self {
+        array.as_c_str().to_owned()
+    }
+}
+impl<const LEN: usize> From<CStrArray<LEN>> for [u8; LEN] {
+    fn from(array: CStrArray<LEN>) -> Self {
+        array.bytes
+    }
+}
+impl<const LEN: usize> RawcodeConstSize for CStrArray<LEN> {
+    const SIZE: usize = <[u8; LEN]>::SIZE;
+}
+impl<const LEN: usize> RawcodeEncode for CStrArray<LEN> {
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        self.bytes.encode(buf)
+    }
+}
+impl<const LEN: usize> RawcodeDecode for CStrArray<LEN> {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        let bytes = <[u8; LEN]>::decode(buf)?;
+        Self::try_from(bytes)
+    }
+}
@@ -0,0 +1,123 @@
+//! Implements cursor-style encoders and decoders
+
+use crate::{
+    coding::{self, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+
+/// A cursor that encodes consecutive values into a buffer
+#[derive(Debug)]
+pub struct Encoder<'a> {
+    /// The target buffer
+    buf: &'a mut [u8],
+    /// The current position within the buffer
+    pos: usize,
+}
+impl<'a> Encoder<'a> {
+    /// Creates a new encoder that writes to `buf`
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// Encodes `value` at the current position and advances the position accordingly
+    pub fn put<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: RawcodeEncode,
+    {
+        coding::to_slice_at(value, self.buf, &mut self.pos)
+    }
+    /// Skips `len` bytes and fills them with zero
+    pub fn skip(&mut self, len: usize) -> Result<(), Error> {
+        // Get the skipped bytes
+        let (expected, actual) = (self.pos.saturating_add(len), self.buf.len());
+        let skipped = self.buf.get_mut(self.pos..expected);
+        let skipped = skipped.ok_or(e!(BufferTooSmall { expected, actual }, "Truncated buffer"))?;
+
+        // Zero the bytes and advance the position
+        skipped.fill(0);
+        self.pos = expected;
+        Ok(())
+    }
+
+    /// The current position within the buffer
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+    /// The amount of remaining bytes
+    pub const fn remaining(&self) -> usize {
+        self.buf.len().saturating_sub(self.pos)
+    }
+
+    /// Ensures that the buffer has been filled completely
+    pub fn finish(self) -> Result<(), Error> {
+        match self.buf.len() {
+            len if len > self.pos => Err(e!(BufferTooLarge { expected: self.pos, actual: len })),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A cursor that decodes consecutive values from a buffer
+#[derive(Debug, Clone)]
+pub struct Decoder<'a> {
+    /// The input buffer
+    buf: &'a [u8],
+    /// The current position within the buffer
+    pos: usize,
+}
+impl<'a> Decoder<'a> {
+    /// Creates a new decoder that reads from `buf`
+    pub const fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// Decodes a `T` at the current position and advances the position accordingly
+    pub fn get<T>(&mut self) -> Result<T, Error>
+    where
+        T: RawcodeDecode,
+    {
+        coding::from_slice_at(self.buf, &mut self.pos)
+    }
+    /// Decodes a `T` at the current position without advancing the position
+    pub fn peek<T>(&self) -> Result<T, Error>
+    where
+        T: RawcodeDecode,
+    {
+        let mut pos = self.pos;
+        coding::from_slice_at(self.buf, &mut pos)
+    }
+    /// Validates a `T` at the current position, reports all errors and advances the position accordingly
+    pub fn validate<T>(&mut self, report: &mut dyn FnMut(Error))
+    where
+        T: RawcodeDecode,
+    {
+        coding::validate_at::<T>(self.buf, &mut self.pos, report)
+    }
+    /// Skips `len` bytes
+    pub fn skip(&mut self, len: usize) -> Result<(), Error> {
+        match (self.pos.saturating_add(len), self.buf.len()) {
+            (expected, actual) if expected > actual => Err(e!(TooShort { expected, actual }, "Truncated data")),
+            (expected, _) => {
+                self.pos = expected;
+                Ok(())
+            }
+        }
+    }
+
+    /// The current position within the buffer
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+    /// The amount of remaining bytes
+    pub const fn remaining(&self) -> usize {
+        self.buf.len().saturating_sub(self.pos)
+    }
+
+    /// Ensures that the buffer has been consumed completely
+    pub fn finish(self) -> Result<(), Error> {
+        match self.buf.len() {
+            len if len > self.pos => Err(e!(TooLong { expected: self.pos, actual: len })),
+            _ => Ok(()),
+        }
+    }
+}
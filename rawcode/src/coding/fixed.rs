@@ -0,0 +1,160 @@
+//! Implements fixed-point number coding
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// A fixed-point number with `FRAC` fractional bits that is backed by the integer `I`
+///
+/// # Encoding
+/// The number is encoded exactly like the underlying integer (i.e. the integer value is `number * 2^FRAC`).
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<I, const FRAC: u32> {
+    /// The raw integer representation
+    bits: I,
+}
+impl<I, const FRAC: u32> Fixed<I, FRAC> {
+    /// Creates a new fixed-point number from it's raw integer representation
+    pub const fn from_bits(bits: I) -> Self {
+        Self { bits }
+    }
+}
+impl<I, const FRAC: u32> Fixed<I, FRAC>
+where
+    I: Copy,
+{
+    /// The raw integer representation
+    pub const fn to_bits(self) -> I {
+        self.bits
+    }
+}
+
+/// Implements the fixed-point operations for the given backing integer and it's double-width integer
+macro_rules! impl_fixed {
+    ($type:ty => $wide:ty) => {
+        impl<const FRAC: u32> Fixed<$type, FRAC> {
+            /// The amount of fractional bits
+            pub const FRAC_BITS: u32 = {
+                assert!(FRAC <= <$type>::BITS, "Too many fractional bits");
+                FRAC
+            };
+            /// The smallest representable value
+            pub const MIN: Self = Self::from_bits(<$type>::MIN);
+            /// The largest representable value
+            pub const MAX: Self = Self::from_bits(<$type>::MAX);
+            /// The scale factor (i.e. `2^FRAC`)
+            const SCALE: f64 = (1u128 << Self::FRAC_BITS) as f64;
+
+            /// Converts `value` into the nearest fixed-point number
+            pub fn from_f64(value: f64) -> Result<Self, Error> {
+                // Round half away from zero; this saturates on overflow which is caught by the range check below
+                //  - note: the fraction is computed from the truncated value because adding `0.5` to the scaled value
+                //    may itself round up (e.g. for `0.49999999999999994`)
+                let scaled = value * Self::SCALE;
+                let truncated = scaled as i128;
+                let rounded = match scaled - truncated as f64 {
+                    _ if value.is_nan() => return Err(e!(Validation, "Value is not a number")),
+                    fraction if fraction >= 0.5 => truncated.saturating_add(1),
+                    fraction if fraction <= -0.5 => truncated.saturating_sub(1),
+                    _ => truncated,
+                };
+
+                // Validate the range
+                let bits = <$type>::try_from(rounded).map_err(|_| e!(OutOfRange, "Value is out of range"))?;
+                Ok(Self::from_bits(bits))
+            }
+            /// Converts `value` into the nearest fixed-point number
+            pub fn from_f32(value: f32) -> Result<Self, Error> {
+                Self::from_f64(f64::from(value))
+            }
+            /// Converts `self` into a floating-point number
+            pub fn to_f64(self) -> f64 {
+                self.bits as f64 / Self::SCALE
+            }
+            /// Converts `self` into a floating-point number
+            pub fn to_f32(self) -> f32 {
+                self.to_f64() as f32
+            }
+
+            /// Checked addition; returns `None` on overflow
+            pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.bits.checked_add(rhs.bits) {
+                    Some(bits) => Some(Self::from_bits(bits)),
+                    None => None,
+                }
+            }
+            /// Checked subtraction; returns `None` on overflow
+            pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+                match self.bits.checked_sub(rhs.bits) {
+                    Some(bits) => Some(Self::from_bits(bits)),
+                    None => None,
+                }
+            }
+            /// Checked multiplication (rounded towards negative infinity); returns `None` on overflow
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                let product = (<$wide>::from(self.bits) * <$wide>::from(rhs.bits)) >> Self::FRAC_BITS;
+                let bits = <$type>::try_from(product).ok()?;
+                Some(Self::from_bits(bits))
+            }
+            /// Checked division (rounded towards zero); returns `None` on overflow or if `rhs` is zero
+            pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                let quotient = (<$wide>::from(self.bits) << Self::FRAC_BITS).checked_div(<$wide>::from(rhs.bits))?;
+                let bits = <$type>::try_from(quotient).ok()?;
+                Some(Self::from_bits(bits))
+            }
+        }
+        impl<const FRAC: u32> Debug for Fixed<$type, FRAC> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.debug_tuple("Fixed").field(&self.to_f64()).finish()
+            }
+        }
+        impl<const FRAC: u32> Display for Fixed<$type, FRAC> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                Display::fmt(&self.to_f64(), f)
+            }
+        }
+    };
+}
+impl_fixed!(u8 => u16);
+impl_fixed!(u16 => u32);
+impl_fixed!(u32 => u64);
+impl_fixed!(u64 => u128);
+impl_fixed!(i8 => i16);
+impl_fixed!(i16 => i32);
+impl_fixed!(i32 => i64);
+impl_fixed!(i64 => i128);
+
+impl<I, const FRAC: u32> RawcodeConstSize for Fixed<I, FRAC>
+where
+    I: RawcodeConstSize,
+{
+    const SIZE: usize = I::SIZE;
+}
+impl<I, const FRAC: u32> RawcodeDecode for Fixed<I, FRAC>
+where
+    I: RawcodeDecode,
+{
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        let bits = I::decode(buf)?;
+        Ok(Self::from_bits(bits))
+    }
+}
+impl<I, const FRAC: u32> RawcodeEncode for Fixed<I, FRAC>
+where
+    I: RawcodeEncode,
+{
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        self.bits.encode(buf)
+    }
+}
+
+/// A signed Q8.8 fixed-point number
+pub type I8F8 = Fixed<i16, 8>;
+/// A signed Q16.16 fixed-point number
+pub type I16F16 = Fixed<i32, 16>;
+/// An unsigned Q8.8 fixed-point number
+pub type U8F8 = Fixed<u16, 8>;
+/// An unsigned Q16.16 fixed-point number
+pub type U16F16 = Fixed<u32, 16>;
@@ -0,0 +1,175 @@
+//! Implements the encoding of typed flag sets
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+/// A flag that can be stored in a `FlagSet`
+pub trait Flag
+where
+    Self: Copy + Sized + 'static,
+{
+    /// The integer type that is used to encode a `FlagSet<Self>`
+    type Bits: RawcodeEncode + RawcodeDecode + Copy + Into<u128> + TryFrom<u128>;
+    /// All flags
+    const ALL: &'static [Self];
+
+    /// The bit index of the flag within `Self::Bits`
+    fn bit(self) -> u32;
+}
+
+/// A set of flags which is encoded as `F::Bits` with bit `flag.bit()` set for every contained flag
+///
+/// # Encoding
+/// During decoding, set bits that do not correspond to a flag in `F::ALL` are rejected.
+pub struct FlagSet<F> {
+    /// The flag bits
+    bits: u128,
+    /// The flag type
+    _flag: PhantomData<F>,
+}
+impl<F> FlagSet<F>
+where
+    F: Flag,
+{
+    /// Creates a new empty flag set
+    pub const fn new() -> Self {
+        Self { bits: 0, _flag: PhantomData }
+    }
+
+    /// Inserts `flag` into the set
+    pub fn insert(&mut self, flag: F) {
+        self.bits |= Self::mask(flag);
+    }
+    /// Removes `flag` from the set
+    pub fn remove(&mut self, flag: F) {
+        self.bits &= !Self::mask(flag);
+    }
+    /// Tests whether the set contains `flag`
+    pub fn contains(&self, flag: F) -> bool {
+        self.bits & Self::mask(flag) != 0
+    }
+
+    /// Whether the set is empty or not
+    pub const fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+    /// An iterator over all contained flags (in order of `F::ALL`)
+    pub fn iter(&self) -> impl Iterator<Item = F> + '_ {
+        F::ALL.iter().copied().filter(|flag| self.contains(*flag))
+    }
+
+    /// The bit mask of `flag`
+    ///
+    /// # Panic
+    /// Panics if the bit index of `flag` is too large
+    fn mask(flag: F) -> u128 {
+        Self::checked_mask(flag).expect("Flag bit index is too large")
+    }
+    /// The bit mask of `flag` if the bit index of `flag` is valid
+    fn checked_mask(flag: F) -> Result<u128, Error> {
+        1u128.checked_shl(flag.bit()).ok_or_else(|| e!(OutOfRange, "Flag bit index is too large"))
+    }
+}
+impl<F> Default for FlagSet<F>
+where
+    F: Flag,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<F> Clone for FlagSet<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<F> Copy for FlagSet<F> {
+    /* No members to implement */
+}
+impl<F> PartialEq for FlagSet<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+impl<F> Eq for FlagSet<F> {
+    /* No members to implement */
+}
+impl<F> PartialOrd for FlagSet<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<F> Ord for FlagSet<F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bits.cmp(&other.bits)
+    }
+}
+impl<F> Hash for FlagSet<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits.hash(state);
+    }
+}
+impl<F> Debug for FlagSet<F>
+where
+    F: Flag + Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+impl<F> FromIterator<F> for FlagSet<F>
+where
+    F: Flag,
+{
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        let mut this = Self::new();
+        iter.into_iter().for_each(|flag| this.insert(flag));
+        this
+    }
+}
+impl<F, const LEN: usize> From<[F; LEN]> for FlagSet<F>
+where
+    F: Flag,
+{
+    fn from(flags: [F; LEN]) -> Self {
+        Self::from_iter(flags)
+    }
+}
+impl<F> RawcodeConstSize for FlagSet<F>
+where
+    F: Flag,
+{
+    const SIZE: usize = F::Bits::SIZE;
+}
+impl<F> RawcodeDecode for FlagSet<F>
+where
+    F: Flag,
+{
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        // Decode the bits and validate that only known flags are set
+        let bits: u128 = F::Bits::decode(buf)?.into();
+        let known = F::ALL.iter().try_fold(0, |known, flag| Ok::<_, Error>(known | Self::checked_mask(*flag)?))?;
+        match bits & !known {
+            0 => Ok(Self { bits, _flag: PhantomData }),
+            _ => Err(e!(Validation, "Unknown flag bits")),
+        }
+    }
+}
+impl<F> RawcodeEncode for FlagSet<F>
+where
+    F: Flag,
+{
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        let bits =
+            F::Bits::try_from(self.bits).map_err(|_| e!(OutOfRange, "Flag bit index exceeds the encoded width"))?;
+        bits.encode(buf)
+    }
+}
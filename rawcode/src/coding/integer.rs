@@ -14,8 +14,8 @@ impl RawcodeDecode for u8 {
     fn decode(buf: &[u8]) -> Result<Self, Error> {
         // Validate the input length
         match buf.len() {
-            len if len > Self::SIZE => Err(e!("Encoded data is too long")),
-            len if len < Self::SIZE => Err(e!("Encoded data is too short")),
+            len if len > Self::SIZE => Err(e!(TooLong { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => Err(e!(TooShort { expected: Self::SIZE, actual: len })),
             _ => Ok(buf[0]),
         }
     }
@@ -24,8 +24,8 @@ impl RawcodeEncode for u8 {
     fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
         // Validate the buffer length
         match buf.len() {
-            len if len > Self::SIZE => return Err(e!("Target buffer is too large")),
-            len if len < Self::SIZE => return Err(e!("Target buffer is too small")),
+            len if len > Self::SIZE => return Err(e!(BufferTooLarge { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(BufferTooSmall { expected: Self::SIZE, actual: len })),
             _ => buf[0] = *self,
         }
         Ok(())
@@ -0,0 +1,120 @@
+//! Implements `std::io` integration
+
+use crate::{
+    coding::{self, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+use std::{
+    io::{self, Read, Write},
+    marker::PhantomData,
+};
+
+/// Reads until `buf` is full or EOF is reached and returns the amount of bytes read
+fn read_full<R>(reader: &mut R, buf: &mut [u8]) -> Result<usize, Error>
+where
+    R: Read,
+{
+    let mut pos = 0;
+    while pos < buf.len() {
+        match reader.read(&mut buf[pos..]) {
+            Ok(0) => break,
+            Ok(read) => pos += read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(e!(Io, "Failed to read from reader").with_source(error)),
+        }
+    }
+    Ok(pos)
+}
+
+/// Reads and decodes a `T` from `reader`
+pub fn read_from<T, R>(reader: &mut R) -> Result<T, Error>
+where
+    T: RawcodeDecode,
+    R: Read,
+{
+    // Read the record
+    let mut buf = vec![0; T::SIZE];
+    match read_full(reader, &mut buf)? {
+        len if len < T::SIZE => Err(e!(TruncatedRecord { expected: T::SIZE, actual: len })),
+        _ => T::decode(&buf),
+    }
+}
+/// Encodes and writes `value` to `writer`
+pub fn write_to<T, W>(value: &T, writer: &mut W) -> Result<(), Error>
+where
+    T: RawcodeEncode,
+    W: Write,
+{
+    let buf = coding::to_vec(value)?;
+    writer.write_all(&buf).map_err(|error| e!(Io, "Failed to write to writer").with_source(error))
+}
+
+/// An iterator that reads and decodes consecutive `T` records from a reader until EOF
+///
+/// # Errors
+/// A record that fails to decode yields an error but does not stop the iteration since the following records are
+/// still aligned; a truncated trailing record (`ErrorKind::TruncatedRecord`) or an I/O error (`ErrorKind::Io`) is
+/// yielded once and ends the iteration.
+#[derive(Debug)]
+pub struct RecordReader<T, R> {
+    /// The underlying reader
+    reader: R,
+    /// The record buffer
+    buf: Vec<u8>,
+    /// The index of the next record
+    index: usize,
+    /// Whether the iteration has ended or not
+    done: bool,
+    /// The record type
+    _record: PhantomData<T>,
+}
+impl<T, R> RecordReader<T, R>
+where
+    T: RawcodeDecode,
+    R: Read,
+{
+    /// Creates a new record reader
+    pub fn new(reader: R) -> Self {
+        Self { reader, buf: vec![0; T::SIZE], index: 0, done: false, _record: PhantomData }
+    }
+
+    /// Returns the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+impl<T, R> Iterator for RecordReader<T, R>
+where
+    T: RawcodeDecode,
+    R: Read,
+{
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Read the next record
+        let (index, offset) = (self.index, self.index * T::SIZE);
+        let result = match self.done {
+            true => return None,
+            false => read_full(&mut self.reader, &mut self.buf),
+        };
+
+        // Decode the record
+        self.index += 1;
+        let result = match result {
+            Ok(0) => {
+                self.done = true;
+                return None;
+            }
+            Ok(len) if len < T::SIZE => {
+                self.done = true;
+                Err(e!(TruncatedRecord { expected: T::SIZE, actual: len }))
+            }
+            Ok(_) => T::decode(&self.buf),
+            Err(error) => {
+                self.done = true;
+                Err(error)
+            }
+        };
+        Some(result.map_err(|error| error.with_index(index).with_offset(offset)))
+    }
+}
@@ -0,0 +1,137 @@
+//! Implements the encoding of Latin-1 arrays
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Debug, Display, Formatter, Write};
+
+/// An ISO-8859-1 (Latin-1) string array
+///
+/// # Encoding
+/// Every byte represents the Unicode code point with the same value (i.e. `U+0000..=U+00FF`); therefore every byte
+/// array is a valid Latin-1 string, and every string that only consists of code points up to `U+00FF` can be
+/// converted losslessly.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Latin1Array<const LEN: usize> {
+    /// The underlying bytes
+    latin1_bytes: [u8; LEN],
+}
+impl<const LEN: usize> Latin1Array<LEN> {
+    /// Creates a new `Latin1Array`
+    pub const fn new(bytes: &[u8; LEN]) -> Self {
+        Self { latin1_bytes: *bytes }
+    }
+
+    /// The underlying bytes
+    pub const fn as_bytes(&self) -> &[u8; LEN] {
+        &self.latin1_bytes
+    }
+    /// An iterator over the decoded characters
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.latin1_bytes.iter().map(|byte| char::from(*byte))
+    }
+}
+impl<const LEN: usize> Debug for Latin1Array<LEN> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("Latin1Array { latin1_bytes: \"")?;
+        self.chars().try_for_each(|char| write!(f, "{}", char.escape_debug()))?;
+        f.write_str("\" }")
+    }
+}
+impl<const LEN: usize> Display for Latin1Array<LEN> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.chars().try_for_each(|char| f.write_char(char))
+    }
+}
+impl<const LEN: usize> TryFrom<&str> for Latin1Array<LEN> {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        // Map the characters to their Latin-1 representation
+        let mut latin1_bytes = [0; LEN];
+        let mut chars = value.chars();
+        for byte in latin1_bytes.iter_mut() {
+            let char = chars.next().ok_or(e!(InvalidLength, "Invalid string length"))?;
+            *byte = u8::try_from(char).map_err(|_| e!(InvalidText, "String contains non-Latin-1 characters"))?;
+        }
+
+        // Ensure that the string has been consumed completely
+        match chars.next() {
+            None => Ok(Self { latin1_bytes }),
+            Some(_) => Err(e!(InvalidLength, "Invalid string length")),
+        }
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const LEN: usize> TryFrom<String> for Latin1Array<LEN> {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+impl<const LEN: usize> From<[u8; LEN]> for Latin1Array<LEN> {
+    fn from(bytes: [u8; LEN]) -> Self {
+        Self { latin1_bytes: bytes }
+    }
+}
+impl<const LEN: usize> TryFrom<&[u8]> for Latin1Array<LEN> {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = <[u8; LEN]>::try_from(value).map_err(|_| e!(InvalidLength, "Invalid slice length"))?;
+        Ok(Self::from(bytes))
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const LEN: usize> TryFrom<Vec<u8>> for Latin1Array<LEN> {
+    type Error = Error;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
+    }
+}
+impl<const LEN: usize> AsRef<[u8]> for Latin1Array<LEN> {
+    fn as_ref(&self) -> &[u8] {
+        &self.latin1_bytes
+    }
+}
+impl<const LEN: usize> AsRef<[u8; LEN]> for Latin1Array<LEN> {
+    fn as_ref(&self) -> &[u8; LEN] {
+        &self.latin1_bytes
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const LEN: usize> From<Latin1Array<LEN>> for String {
+    fn from(array: Latin1Array<LEN>) -> Self {
+        array.chars().collect()
+    }
+}
+impl<const LEN: usize> From<Latin1Array<LEN>> for [u8; LEN] {
+    fn from(array: Latin1Array<LEN>) -> Self {
+        array.latin1_bytes
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const LEN: usize> From<Latin1Array<LEN>> for Vec<u8> {
+    fn from(array: Latin1Array<LEN>) -> Self {
+        array.latin1_bytes.to_vec()
+    }
+}
+impl<const LEN: usize> RawcodeConstSize for Latin1Array<LEN> {
+    const SIZE: usize = <[u8; LEN]>::SIZE;
+}
+impl<const LEN: usize> RawcodeEncode for Latin1Array<LEN> {
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        self.latin1_bytes.encode(buf)
+    }
+}
+impl<const LEN: usize> RawcodeDecode for Latin1Array<LEN> {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        let bytes = <[u8; LEN]>::decode(buf)?;
+        Ok(Self::from(bytes))
+    }
+}
@@ -1,13 +1,68 @@
 //! Defines the basic encoding traits
 
 mod array;
+mod asciiarray;
+mod bcd;
+mod bitarray;
 mod boolean;
+mod boundedvec;
+mod calendar;
+mod cstrarray;
+mod cursor;
+mod fixed;
+mod flagset;
 mod integer;
+#[cfg(feature = "std")]
+mod io;
+mod latin1array;
+mod net;
+mod oddint;
+mod offsetbinary;
+mod paddedstr;
+#[cfg(feature = "alloc")]
+mod pointer;
+mod ranged;
+mod size;
+mod staging;
 mod strarray;
+mod stream;
+mod time;
 mod unit;
+mod utf16array;
+mod uuid;
 
-pub use crate::coding::{strarray::StrArray, unit::Unit};
+pub(crate) use crate::coding::staging::Staging;
+pub use crate::coding::{
+    asciiarray::{AsciiArray, PrintableAsciiArray},
+    bcd::Bcd,
+    bitarray::BitArray,
+    boundedvec::BoundedVec,
+    calendar::{Date, DateTime, DosDateTime, TimeOfDay},
+    cstrarray::CStrArray,
+    cursor::{Decoder, Encoder},
+    fixed::{Fixed, I16F16, I8F8, U16F16, U8F8},
+    flagset::{Flag, FlagSet},
+    latin1array::Latin1Array,
+    net::{Eui64, MacAddr},
+    oddint::{I24, I48, U24, U40, U48, U56},
+    offsetbinary::OffsetBinary,
+    paddedstr::{PaddedStr, SpacePaddedStr},
+    ranged::Ranged,
+    size::{IsizeAs, UsizeAs},
+    strarray::StrArray,
+    stream::{StreamDecoder, StreamRecords},
+    unit::Unit,
+    utf16array::{PaddedUtf16Array, Utf16Array},
+    uuid::{Guid, MixedEndian, Rfc4122, Uuid16, UuidLayout, UuidVariant},
+};
+#[cfg(feature = "std")]
+pub use crate::coding::{
+    io::{read_from, write_to, RecordReader},
+    time::{UnixMillis, UnixNanos, UnixSeconds},
+};
 use crate::error::Error;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
 
 /// A rawcode type with a const size
 pub trait RawcodeConstSize {
@@ -36,6 +91,17 @@ where
 {
     /// Decodes `Self` from `buf`
     fn decode(buf: &[u8]) -> Result<Self, Error>;
+
+    /// Validates the encoded `Self` in `buf` and reports all errors instead of stopping at the first one
+    ///
+    /// # Note
+    /// The default implementation reports the error of [`Self::decode`] if any; composite types (i.e. arrays and
+    /// derived structs) validate each member individually.
+    fn validate(buf: &[u8], report: &mut dyn FnMut(Error)) {
+        if let Err(error) = Self::decode(buf) {
+            report(error);
+        }
+    }
 }
 
 /// Encodes `value` to `buf`
@@ -51,15 +117,16 @@ where
     T: RawcodeEncode,
 {
     // Create the buffer and encode the value
-    let buf = buf.get_mut(*pos..).and_then(|buf| buf.get_mut(..T::SIZE)).ok_or(e!("Truncated buffer"))?;
-    value.encode(buf)?;
+    let (expected, actual) = (pos.saturating_add(T::SIZE), buf.len());
+    let buf = buf.get_mut(*pos..expected).ok_or(e!(BufferTooSmall { expected, actual }, "Truncated buffer"))?;
+    value.encode(buf).map_err(|error| error.with_offset(*pos))?;
 
     // Advance the position
     *pos += T::SIZE;
     Ok(())
 }
 /// Encodes `value`
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub fn to_vec<T>(value: &T) -> Result<Vec<u8>, Error>
 where
     T: RawcodeEncode,
@@ -82,10 +149,37 @@ where
     T: RawcodeDecode,
 {
     // Create the buffer and encode the value
-    let buf = buf.get(*pos..).and_then(|buf| buf.get(..T::SIZE)).ok_or(e!("Truncated data"))?;
-    let value = T::decode(buf)?;
+    let (expected, actual) = (pos.saturating_add(T::SIZE), buf.len());
+    let buf = buf.get(*pos..expected).ok_or(e!(TooShort { expected, actual }, "Truncated data"))?;
+    let value = T::decode(buf).map_err(|error| error.with_offset(*pos))?;
 
     // Advance the position
     *pos += T::SIZE;
     Ok(value)
 }
+
+/// Validates `buf` as `T` and collects all errors
+#[cfg(feature = "alloc")]
+pub fn validate<T>(buf: &[u8]) -> Vec<Error>
+where
+    T: RawcodeDecode,
+{
+    let mut errors = Vec::new();
+    T::validate(buf, &mut |error| errors.push(error));
+    errors
+}
+/// Validates `T` in `buf` at the given `pos`, reports all errors and increments the position accordingly
+pub fn validate_at<T>(buf: &[u8], pos: &mut usize, report: &mut dyn FnMut(Error))
+where
+    T: RawcodeDecode,
+{
+    // Validate the value
+    let (start, expected, actual) = (*pos, pos.saturating_add(T::SIZE), buf.len());
+    match buf.get(start..expected) {
+        Some(buf) => T::validate(buf, &mut |error| report(error.with_offset(start))),
+        None => report(e!(TooShort { expected, actual }, "Truncated data")),
+    }
+
+    // Advance the position
+    *pos = expected;
+}
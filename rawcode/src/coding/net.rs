@@ -0,0 +1,205 @@
+//! Implements the encoding of network addresses
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+use core::{
+    fmt::{self, Display, Formatter},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6},
+};
+
+// Implements rawcode for `Ipv4Addr` as it's octets (i.e. network byte order)
+impl RawcodeConstSize for Ipv4Addr {
+    const SIZE: usize = 4;
+}
+impl RawcodeDecode for Ipv4Addr {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        let octets = <[u8; 4]>::decode(buf)?;
+        Ok(Self::from(octets))
+    }
+}
+impl RawcodeEncode for Ipv4Addr {
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        self.octets().encode(buf)
+    }
+}
+
+// Implements rawcode for `Ipv6Addr` as it's octets (i.e. network byte order)
+impl RawcodeConstSize for Ipv6Addr {
+    const SIZE: usize = 16;
+}
+impl RawcodeDecode for Ipv6Addr {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        let octets = <[u8; 16]>::decode(buf)?;
+        Ok(Self::from(octets))
+    }
+}
+impl RawcodeEncode for Ipv6Addr {
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        self.octets().encode(buf)
+    }
+}
+
+// Implements rawcode for `IpAddr` as tag (`u8`, `4` or `6`) followed by the address where IPv4 addresses are padded
+// with zero bytes to the size of an IPv6 address
+impl RawcodeConstSize for IpAddr {
+    const SIZE: usize = u8::SIZE + Ipv6Addr::SIZE;
+}
+impl RawcodeDecode for IpAddr {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        // Decode the tag
+        let (tag, address) = match buf.len() {
+            len if len > Self::SIZE => return Err(e!(TooLong { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(TooShort { expected: Self::SIZE, actual: len })),
+            _ => buf.split_at(u8::SIZE),
+        };
+
+        // Decode the address
+        let (ipv4, padding) = address.split_at(Ipv4Addr::SIZE);
+        match u8::decode(tag)? {
+            4 if padding.iter().any(|byte| *byte != 0) => {
+                Err(e!(InvalidPadding, "IPv4 address padding is not zero-filled"))
+            }
+            4 => Ok(Self::V4(Ipv4Addr::decode(ipv4)?)),
+            6 => Ok(Self::V6(Ipv6Addr::decode(address)?)),
+            _ => Err(e!(InvalidTag, "Invalid address tag")),
+        }
+    }
+}
+impl RawcodeEncode for IpAddr {
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        // Validate the buffer length
+        let (tag, address) = match buf.len() {
+            len if len > Self::SIZE => return Err(e!(BufferTooLarge { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(BufferTooSmall { expected: Self::SIZE, actual: len })),
+            _ => buf.split_at_mut(u8::SIZE),
+        };
+
+        // Encode the tag and the address
+        match self {
+            Self::V4(ipv4) => {
+                let (address, padding) = address.split_at_mut(Ipv4Addr::SIZE);
+                4u8.encode(tag)?;
+                ipv4.encode(address)?;
+                padding.fill(0);
+                Ok(())
+            }
+            Self::V6(ipv6) => {
+                6u8.encode(tag)?;
+                ipv6.encode(address)
+            }
+        }
+    }
+}
+
+/// Implements rawcode for a socket address type as address followed by the port (`u16`, network byte order)
+macro_rules! impl_socket_addr {
+    ($(#[$meta:meta])* $type:ty, $address:ty, $new:expr) => {
+        impl RawcodeConstSize for $type {
+            const SIZE: usize = <$address>::SIZE + u16::SIZE;
+        }
+        $(#[$meta])*
+        impl RawcodeDecode for $type {
+            fn decode(buf: &[u8]) -> Result<Self, Error> {
+                // Decode the address and the port
+                let (address, port) = match buf.len() {
+                    len if len > Self::SIZE => return Err(e!(TooLong { expected: Self::SIZE, actual: len })),
+                    len if len < Self::SIZE => return Err(e!(TooShort { expected: Self::SIZE, actual: len })),
+                    _ => buf.split_at(<$address>::SIZE),
+                };
+                let address = <$address>::decode(address)?;
+                let port = u16::from_be_bytes(<[u8; 2]>::decode(port)?);
+                Ok($new(address, port))
+            }
+        }
+        $(#[$meta])*
+        impl RawcodeEncode for $type {
+            fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+                // Encode the address and the port
+                let (address, port) = match buf.len() {
+                    len if len > Self::SIZE => return Err(e!(BufferTooLarge { expected: Self::SIZE, actual: len })),
+                    len if len < Self::SIZE => return Err(e!(BufferTooSmall { expected: Self::SIZE, actual: len })),
+                    _ => buf.split_at_mut(<$address>::SIZE),
+                };
+                self.ip().encode(address)?;
+                self.port().to_be_bytes().encode(port)
+            }
+        }
+    };
+}
+impl_socket_addr!(SocketAddrV4, Ipv4Addr, SocketAddrV4::new);
+impl_socket_addr! {
+    /// Encodes a `SocketAddrV6` as address followed by the port in network byte order
+    ///
+    /// # Lossy Encoding
+    /// The flow info and scope ID are not encoded and are always decoded as `0`; i.e. a link-local address with a scope
+    /// ID will not roundtrip.
+    SocketAddrV6, Ipv6Addr, |address, port| SocketAddrV6::new(address, port, 0, 0)
+}
+
+/// Implements a hardware address type that is encoded as it's octets
+macro_rules! impl_hardware_addr {
+    ($(#[$meta:meta])* $type:ident($len:expr)) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $type {
+            /// The address octets
+            octets: [u8; $len],
+        }
+        impl $type {
+            /// Creates a new address from it's octets
+            pub const fn new(octets: [u8; $len]) -> Self {
+                Self { octets }
+            }
+
+            /// The address octets
+            pub const fn octets(&self) -> [u8; $len] {
+                self.octets
+            }
+        }
+        impl Display for $type {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                for (index, octet) in self.octets.iter().enumerate() {
+                    match index {
+                        0 => write!(f, "{octet:02x}")?,
+                        _ => write!(f, ":{octet:02x}")?,
+                    }
+                }
+                Ok(())
+            }
+        }
+        impl From<[u8; $len]> for $type {
+            fn from(octets: [u8; $len]) -> Self {
+                Self::new(octets)
+            }
+        }
+        impl From<$type> for [u8; $len] {
+            fn from(address: $type) -> Self {
+                address.octets
+            }
+        }
+        impl RawcodeConstSize for $type {
+            const SIZE: usize = $len;
+        }
+        impl RawcodeDecode for $type {
+            fn decode(buf: &[u8]) -> Result<Self, Error> {
+                let octets = <[u8; $len]>::decode(buf)?;
+                Ok(Self::new(octets))
+            }
+        }
+        impl RawcodeEncode for $type {
+            fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+                self.octets.encode(buf)
+            }
+        }
+    };
+}
+impl_hardware_addr!(
+    /// A 48 bit MAC address (EUI-48)
+    MacAddr(6)
+);
+impl_hardware_addr!(
+    /// A 64 bit extended unique identifier (EUI-64)
+    Eui64(8)
+);
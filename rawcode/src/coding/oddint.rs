@@ -0,0 +1,101 @@
+//! Implements odd-width integer coding
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+
+/// Implements an odd-width integer type that is backed by a native integer
+macro_rules! impl_oddint {
+    ($(#[$meta:meta])* $type:ident($backing:ty, $unsigned:ty, $bytes:expr, $min:expr, $max:expr)) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $type {
+            /// The underlying value
+            value: $backing,
+        }
+        impl $type {
+            /// The smallest representable value
+            pub const MIN: Self = Self { value: $min };
+            /// The largest representable value
+            pub const MAX: Self = Self { value: $max };
+            /// The amount of bits of the integer
+            pub const BITS: u32 = $bytes * 8;
+
+            /// Creates a new integer if `value` is within the representable range
+            pub const fn new(value: $backing) -> Option<Self> {
+                // Truncate and sign-extend the value to validate that it is within range
+                let shift = <$backing>::BITS - Self::BITS;
+                match (value << shift) >> shift {
+                    truncated if truncated == value => Some(Self { value }),
+                    _ => None,
+                }
+            }
+
+            /// The underlying value
+            pub const fn get(self) -> $backing {
+                self.value
+            }
+        }
+        impl TryFrom<$backing> for $type {
+            type Error = Error;
+
+            fn try_from(value: $backing) -> Result<Self, Self::Error> {
+                Self::new(value).ok_or(e!(OutOfRange, "Integer value is out of range"))
+            }
+        }
+        impl From<$type> for $backing {
+            fn from(value: $type) -> Self {
+                value.value
+            }
+        }
+        impl RawcodeConstSize for $type {
+            const SIZE: usize = $bytes;
+        }
+        impl RawcodeDecode for $type {
+            fn decode(buf: &[u8]) -> Result<Self, Error> {
+                // Decode the bytes into the lower bytes of the backing integer
+                let bytes = <[u8; $bytes]>::decode(buf)?;
+                let mut full = [0; core::mem::size_of::<$backing>()];
+                full[..$bytes].copy_from_slice(&bytes);
+
+                // Sign-extend the value (this is a no-op for unsigned types)
+                let shift = <$backing>::BITS - Self::BITS;
+                let value = (<$backing>::from_le_bytes(full) << shift) >> shift;
+                Ok(Self { value })
+            }
+        }
+        impl RawcodeEncode for $type {
+            fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+                // Encode the lower bytes of the backing integer
+                let full = (self.value as $unsigned).to_le_bytes();
+                let bytes: [u8; $bytes] = full[..$bytes].try_into().expect("Invalid integer width?!");
+                bytes.encode(buf)
+            }
+        }
+    };
+}
+impl_oddint!(
+    /// A 24 bit unsigned integer
+    U24(u32, u32, 3, 0, 0x00ff_ffff)
+);
+impl_oddint!(
+    /// A 24 bit signed integer
+    I24(i32, u32, 3, -0x0080_0000, 0x007f_ffff)
+);
+impl_oddint!(
+    /// A 40 bit unsigned integer
+    U40(u64, u64, 5, 0, 0x0000_00ff_ffff_ffff)
+);
+impl_oddint!(
+    /// A 48 bit unsigned integer
+    U48(u64, u64, 6, 0, 0x0000_ffff_ffff_ffff)
+);
+impl_oddint!(
+    /// A 48 bit signed integer
+    I48(i64, u64, 6, -0x0000_8000_0000_0000, 0x0000_7fff_ffff_ffff)
+);
+impl_oddint!(
+    /// A 56 bit unsigned integer
+    U56(u64, u64, 7, 0, 0x00ff_ffff_ffff_ffff)
+);
@@ -0,0 +1,70 @@
+//! Implements offset-binary (excess-K) integer coding
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+
+/// A signed integer `T` that is encoded in offset-binary (e.g. `OffsetBinary<i16>`)
+///
+/// # Encoding
+/// The value is encoded as the unsigned integer `value + 2^(BITS - 1)` (i.e. the most negative value is encoded as all
+/// zero bits, zero is encoded as `0x80..`, and the most positive value is encoded as all one bits).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OffsetBinary<T> {
+    /// The underlying value
+    value: T,
+}
+impl<T> OffsetBinary<T> {
+    /// Creates a new offset-binary integer
+    pub const fn new(value: T) -> Self {
+        Self { value }
+    }
+}
+impl<T> OffsetBinary<T>
+where
+    T: Copy,
+{
+    /// The underlying value
+    pub const fn get(self) -> T {
+        self.value
+    }
+}
+impl<T> From<T> for OffsetBinary<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Implements offset-binary coding for the given signed integer and it's unsigned counterpart
+macro_rules! impl_offset_binary {
+    ($type:ty => $unsigned:ty) => {
+        impl From<OffsetBinary<$type>> for $type {
+            fn from(value: OffsetBinary<$type>) -> Self {
+                value.value
+            }
+        }
+        impl RawcodeConstSize for OffsetBinary<$type> {
+            const SIZE: usize = <$unsigned>::SIZE;
+        }
+        impl RawcodeDecode for OffsetBinary<$type> {
+            fn decode(buf: &[u8]) -> Result<Self, Error> {
+                // Flipping the sign bit is equivalent to subtracting the offset
+                let raw = <$unsigned>::decode(buf)?;
+                Ok(Self::new((raw ^ (1 << (<$unsigned>::BITS - 1))) as $type))
+            }
+        }
+        impl RawcodeEncode for OffsetBinary<$type> {
+            fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+                // Flipping the sign bit is equivalent to adding the offset
+                let raw = (self.value as $unsigned) ^ (1 << (<$unsigned>::BITS - 1));
+                raw.encode(buf)
+            }
+        }
+    };
+}
+impl_offset_binary!(i8 => u8);
+impl_offset_binary!(i16 => u16);
+impl_offset_binary!(i32 => u32);
+impl_offset_binary!(i64 => u64);
+impl_offset_binary!(i128 => u128);
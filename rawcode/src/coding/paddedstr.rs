@@ -0,0 +1,164 @@
+//! Implements the encoding of padded strings
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::{Error, ErrorKind},
+};
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::{
+    fmt::{self, Debug, Formatter},
+    ops::Deref,
+    str,
+};
+
+/// A string of up to `LEN` bytes that is padded with `PAD` to exactly `LEN` bytes
+///
+/// # Content rules
+/// The string content must be valid UTF-8, must not contain NUL bytes and must not end with the padding byte (since the
+/// padding could not be distinguished from the content otherwise). During decoding, all trailing padding bytes are
+/// trimmed; if the padding byte is NUL, this means that the first NUL byte terminates the string and that any non-NUL
+/// byte after the terminator is rejected.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PaddedStr<const LEN: usize, const PAD: u8 = 0> {
+    /// The padded bytes
+    bytes: [u8; LEN],
+    /// The length of the content
+    len: usize,
+}
+impl<const LEN: usize, const PAD: u8> PaddedStr<LEN, PAD> {
+    /// Creates a new `PaddedStr`
+    ///
+    /// # Panic
+    /// Panics if the literal is too long or violates the content rules
+    pub const fn new(string: &str) -> Self {
+        match Self::from_content(string.as_bytes()) {
+            Ok(this) => this,
+            Err(_) => panic!("Invalid string literal"),
+        }
+    }
+
+    /// Tries to create a new `PaddedStr` from the given content bytes
+    ///
+    /// # Note
+    /// This function returns the error kind and message instead of an `Error` so that it can be used in const contexts.
+    const fn from_content(content: &[u8]) -> Result<Self, (ErrorKind, &'static str)> {
+        // Validate the content length and the last byte
+        match content {
+            content if content.len() > LEN => return Err((ErrorKind::InvalidLength, "String is too long")),
+            [.., last] if *last == PAD => return Err((ErrorKind::InvalidText, "String ends with the padding byte")),
+            _ => (/* all ok */),
+        }
+
+        // Copy the content and validate that it does not contain NUL bytes
+        let mut bytes = [PAD; LEN];
+        let mut index = 0;
+        while index < content.len() {
+            match content[index] {
+                0x00 => return Err((ErrorKind::InvalidText, "String contains a NUL byte")),
+                byte => bytes[index] = byte,
+            }
+            index += 1;
+        }
+        Ok(Self { bytes, len: content.len() })
+    }
+    /// Tries to create a new `PaddedStr` from the given padded bytes
+    fn from_padded(bytes: [u8; LEN]) -> Result<Self, Error> {
+        // Trim the padding and validate the content
+        let len = bytes.iter().rposition(|byte| *byte != PAD).map(|index| index + 1).unwrap_or(0);
+        match PAD {
+            _ if !bytes[..len].contains(&0x00) => (/* all ok */),
+            0x00 => return Err(e!(InvalidText, "Non-padding byte after terminator")),
+            _ => return Err(e!(InvalidText, "String contains a NUL byte")),
+        }
+        str::from_utf8(&bytes[..len]).map_err(|_| e!(InvalidUtf8, "Array contains non-UTF-8 bytes"))?;
+        Ok(Self { bytes, len })
+    }
+
+    /// The content as string slice
+    pub fn as_str(&self) -> &str {
+        // This is safe because we've already validated the string during construction
+        unsafe { str::from_utf8_unchecked(&self.bytes[..self.len]) }
+    }
+    /// The padded bytes
+    pub const fn as_padded(&self) -> &[u8; LEN] {
+        &self.bytes
+    }
+}
+impl<const LEN: usize, const PAD: u8> Default for PaddedStr<LEN, PAD> {
+    fn default() -> Self {
+        Self { bytes: [PAD; LEN], len: 0 }
+    }
+}
+impl<const LEN: usize, const PAD: u8> Debug for PaddedStr<LEN, PAD> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("PaddedStr").field("string", &self.as_str()).field("pad", &PAD).finish()
+    }
+}
+impl<const LEN: usize, const PAD: u8> TryFrom<&str> for PaddedStr<LEN, PAD> {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_content(value.as_bytes()).map_err(|(kind, message)| Error::new(kind, message))
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const LEN: usize, const PAD: u8> TryFrom<String> for PaddedStr<LEN, PAD> {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+impl<const LEN: usize, const PAD: u8> TryFrom<[u8; LEN]> for PaddedStr<LEN, PAD> {
+    type Error = Error;
+
+    fn try_from(bytes: [u8; LEN]) -> Result<Self, Self::Error> {
+        Self::from_padded(bytes)
+    }
+}
+impl<const LEN: usize, const PAD: u8> Deref for PaddedStr<LEN, PAD> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+impl<const LEN: usize, const PAD: u8> AsRef<str> for PaddedStr<LEN, PAD> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl<const LEN: usize, const PAD: u8> AsRef<[u8]> for PaddedStr<LEN, PAD> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const LEN: usize, const PAD: u8> From<PaddedStr<LEN, PAD>> for String {
+    fn from(string: PaddedStr<LEN, PAD>) -> Self {
+        string.as_str().to_string()
+    }
+}
+impl<const LEN: usize, const PAD: u8> From<PaddedStr<LEN, PAD>> for [u8; LEN] {
+    fn from(string: PaddedStr<LEN, PAD>) -> Self {
+        string.bytes
+    }
+}
+impl<const LEN: usize, const PAD: u8> RawcodeConstSize for PaddedStr<LEN, PAD> {
+    const SIZE: usize = <[u8; LEN]>::SIZE;
+}
+impl<const LEN: usize, const PAD: u8> RawcodeEncode for PaddedStr<LEN, PAD> {
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        self.bytes.encode(buf)
+    }
+}
+impl<const LEN: usize, const PAD: u8> RawcodeDecode for PaddedStr<LEN, PAD> {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        let bytes = <[u8; LEN]>::decode(buf)?;
+        Self::from_padded(bytes)
+    }
+}
+
+/// A string of up to `LEN` bytes that is padded with spaces to exactly `LEN` bytes
+pub type SpacePaddedStr<const LEN: usize> = PaddedStr<LEN, b' '>;
@@ -0,0 +1,46 @@
+//! Implements the encoding of smart pointers
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::{boxed::Box, rc::Rc};
+
+/// Implements rawcode for a smart pointer as the pointee (i.e. the pointer is transparent)
+macro_rules! impl_pointer {
+    ($type:ident) => {
+        impl<T> RawcodeConstSize for $type<T>
+        where
+            T: RawcodeConstSize,
+        {
+            const SIZE: usize = T::SIZE;
+        }
+        impl<T> RawcodeDecode for $type<T>
+        where
+            T: RawcodeDecode,
+        {
+            fn decode(buf: &[u8]) -> Result<Self, Error> {
+                let value = T::decode(buf)?;
+                Ok($type::new(value))
+            }
+
+            fn validate(buf: &[u8], report: &mut dyn FnMut(Error)) {
+                T::validate(buf, report)
+            }
+        }
+        impl<T> RawcodeEncode for $type<T>
+        where
+            T: RawcodeEncode,
+        {
+            fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+                T::encode(self, buf)
+            }
+        }
+    };
+}
+impl_pointer!(Box);
+impl_pointer!(Rc);
+#[cfg(target_has_atomic = "ptr")]
+impl_pointer!(Arc);
@@ -0,0 +1,68 @@
+//! Implements range-restricted integer coding
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+
+/// An integer `T` that is guaranteed to be within `MIN..=MAX` (e.g. `Ranged<u8, 1, 14>`)
+///
+/// # Encoding
+/// The value is encoded exactly like the underlying integer; during decoding, values outside of `MIN..=MAX` are
+/// rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ranged<T, const MIN: i128, const MAX: i128> {
+    /// The underlying value
+    value: T,
+}
+impl<T, const MIN: i128, const MAX: i128> Ranged<T, MIN, MAX>
+where
+    T: Copy + TryInto<i128>,
+{
+    /// The smallest allowed value
+    pub const MIN: i128 = {
+        assert!(MIN <= MAX, "Invalid range");
+        MIN
+    };
+    /// The largest allowed value
+    pub const MAX: i128 = MAX;
+
+    /// Creates a new range-restricted integer if `value` is within `MIN..=MAX`
+    pub fn new(value: T) -> Result<Self, Error> {
+        // Values that cannot be represented as `i128` are always out of range since `MAX` is an `i128`
+        let wide: i128 = value.try_into().map_err(|_| e!(OutOfRange, "Value is out of range"))?;
+        match wide {
+            wide if wide < Self::MIN => Err(e!(OutOfRange, "Value is below the allowed range")),
+            wide if wide > Self::MAX => Err(e!(OutOfRange, "Value is above the allowed range")),
+            _ => Ok(Self { value }),
+        }
+    }
+
+    /// The underlying value
+    pub const fn get(self) -> T {
+        self.value
+    }
+}
+impl<T, const MIN: i128, const MAX: i128> RawcodeConstSize for Ranged<T, MIN, MAX>
+where
+    T: RawcodeConstSize,
+{
+    const SIZE: usize = T::SIZE;
+}
+impl<T, const MIN: i128, const MAX: i128> RawcodeDecode for Ranged<T, MIN, MAX>
+where
+    T: RawcodeDecode + Copy + TryInto<i128>,
+{
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        let value = T::decode(buf)?;
+        Self::new(value)
+    }
+}
+impl<T, const MIN: i128, const MAX: i128> RawcodeEncode for Ranged<T, MIN, MAX>
+where
+    T: RawcodeEncode,
+{
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        self.value.encode(buf)
+    }
+}
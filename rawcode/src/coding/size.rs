@@ -0,0 +1,82 @@
+//! Implements portable `usize` and `isize` coding
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+use core::marker::PhantomData;
+
+/// Implements a wrapper that encodes a platform-dependent integer as a fixed-width wire integer
+macro_rules! impl_size_as {
+    ($(#[$meta:meta])* $type:ident($native:ty)) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $type<T> {
+            /// The underlying value
+            value: $native,
+            /// The wire type
+            _wire: PhantomData<T>,
+        }
+        impl<T> $type<T> {
+            /// Creates a new wrapper
+            pub const fn new(value: $native) -> Self {
+                Self { value, _wire: PhantomData }
+            }
+
+            /// The underlying value
+            pub const fn get(self) -> $native {
+                self.value
+            }
+        }
+        impl<T> Default for $type<T> {
+            fn default() -> Self {
+                Self::new(0)
+            }
+        }
+        impl<T> From<$native> for $type<T> {
+            fn from(value: $native) -> Self {
+                Self::new(value)
+            }
+        }
+        impl<T> From<$type<T>> for $native {
+            fn from(value: $type<T>) -> Self {
+                value.value
+            }
+        }
+        impl<T> RawcodeConstSize for $type<T>
+        where
+            T: RawcodeConstSize,
+        {
+            const SIZE: usize = T::SIZE;
+        }
+        impl<T> RawcodeDecode for $type<T>
+        where
+            T: RawcodeDecode,
+            $native: TryFrom<T>,
+        {
+            fn decode(buf: &[u8]) -> Result<Self, Error> {
+                let wire = T::decode(buf)?;
+                let value = <$native>::try_from(wire)
+                    .map_err(|_| e!(OutOfRange, concat!("Value does not fit into ", stringify!($native))))?;
+                Ok(Self::new(value))
+            }
+        }
+        impl<T> RawcodeEncode for $type<T>
+        where
+            T: RawcodeEncode + TryFrom<$native>,
+        {
+            fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+                let wire = T::try_from(self.value).map_err(|_| e!(OutOfRange, "Value does not fit into wire type"))?;
+                wire.encode(buf)
+            }
+        }
+    };
+}
+impl_size_as!(
+    /// A `usize` that is encoded as the fixed-width wire integer `T` (e.g. `UsizeAs<u32>`)
+    UsizeAs(usize)
+);
+impl_size_as!(
+    /// An `isize` that is encoded as the fixed-width wire integer `T` (e.g. `IsizeAs<i64>`)
+    IsizeAs(isize)
+);
@@ -0,0 +1,43 @@
+//! Implements the staging buffer of incremental decoders
+
+/// A fixed-size buffer that collects the bytes of a record from arbitrarily sized chunks
+#[derive(Debug, Clone)]
+pub(crate) struct Staging<const N: usize> {
+    /// The buffered bytes
+    buf: [u8; N],
+    /// The amount of buffered bytes
+    len: usize,
+}
+impl<const N: usize> Staging<N> {
+    /// Creates a new empty staging buffer
+    pub const fn new() -> Self {
+        Self { buf: [0; N], len: 0 }
+    }
+
+    /// Moves bytes from the front of `chunk` into the buffer and returns whether the buffer is full
+    pub fn fill(&mut self, chunk: &mut &[u8]) -> bool {
+        let (bytes, rest) = chunk.split_at(chunk.len().min(N - self.len));
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+        *chunk = rest;
+        self.len == N
+    }
+    /// Drops the first `len` bytes
+    pub fn consume(&mut self, len: usize) {
+        self.buf.copy_within(len..self.len, 0);
+        self.len -= len;
+    }
+    /// Drops all bytes
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// The buffered bytes
+    pub fn bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+    /// The amount of buffered bytes
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+}
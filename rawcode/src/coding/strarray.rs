@@ -4,6 +4,11 @@ use crate::{
     coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
     error::Error,
 };
+#[cfg(feature = "alloc")]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::{
     fmt::{self, Debug, Formatter},
     ops::Deref,
@@ -75,11 +80,11 @@ impl<const LEN: usize> TryFrom<&str> for StrArray<LEN> {
     type Error = Error;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let utf8_bytes = Utf8Array::from_str(value).ok_or(e!("Invalid string length"))?;
+        let utf8_bytes = Utf8Array::from_str(value).ok_or(e!(InvalidLength, "Invalid string length"))?;
         Ok(Self { utf8_bytes })
     }
 }
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<const LEN: usize> TryFrom<String> for StrArray<LEN> {
     type Error = Error;
 
@@ -91,7 +96,7 @@ impl<const LEN: usize> TryFrom<[u8; LEN]> for StrArray<LEN> {
     type Error = Error;
 
     fn try_from(bytes: [u8; LEN]) -> Result<Self, Self::Error> {
-        let utf8_bytes = Utf8Array::from_array(bytes).ok_or(e!("Array contains non-UTF-8 bytes"))?;
+        let utf8_bytes = Utf8Array::from_array(bytes).ok_or(e!(InvalidUtf8, "Array contains non-UTF-8 bytes"))?;
         Ok(Self { utf8_bytes })
     }
 }
@@ -99,11 +104,11 @@ impl<const LEN: usize> TryFrom<&[u8]> for StrArray<LEN> {
     type Error = Error;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        let bytes = <[u8; LEN]>::try_from(value).map_err(|_| e!("Invalid slice length"))?;
+        let bytes = <[u8; LEN]>::try_from(value).map_err(|_| e!(InvalidLength, "Invalid slice length"))?;
         Self::try_from(bytes)
     }
 }
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<const LEN: usize> TryFrom<Vec<u8>> for StrArray<LEN> {
     type Error = Error;
 
@@ -133,7 +138,7 @@ impl<const LEN: usize> AsRef<[u8; LEN]> for StrArray<LEN> {
         self.utf8_bytes.as_array()
     }
 }
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<const LEN: usize> From<StrArray<LEN>> for String {
     fn from(array: StrArray<LEN>) -> Self {
         array.utf8_bytes.as_str().to_string()
@@ -144,7 +149,7 @@ impl<const LEN: usize> From<StrArray<LEN>> for [u8; LEN] {
         array.utf8_bytes.into_array()
     }
 }
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<const LEN: usize> From<StrArray<LEN>> for Vec<u8> {
     fn from(array: StrArray<LEN>) -> Self {
         array.utf8_bytes.as_array().to_vec()
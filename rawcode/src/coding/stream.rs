@@ -0,0 +1,125 @@
+//! Implements incremental decoding of byte streams
+
+use crate::{
+    coding::{staging::Staging, RawcodeDecode},
+    error::Error,
+};
+use core::marker::PhantomData;
+
+/// An incremental decoder that decodes consecutive `T` records from arbitrarily sized chunks of a byte stream
+///
+/// # Note
+/// `N` is the size of the internal staging buffer and must be equal to `T::SIZE`; this is checked at compile time.
+///
+/// # Errors
+/// A record that fails to decode yields an error but does not stop the decoding since the following records are still
+/// aligned.
+///
+/// # Early Drop
+/// The chunk is always consumed completely to keep the stream aligned. If the records iterator is dropped before it is
+/// exhausted, the records that are completed by the rest of the chunk are skipped without decoding and counted (see
+/// [`Self::skipped`]), so that lost records can be detected.
+#[derive(Debug, Clone)]
+pub struct StreamDecoder<T, const N: usize> {
+    /// The staging buffer for the current record
+    staging: Staging<N>,
+    /// The index of the next record
+    index: usize,
+    /// The amount of skipped records
+    skipped: usize,
+    /// The record type
+    _record: PhantomData<T>,
+}
+impl<T, const N: usize> StreamDecoder<T, N>
+where
+    T: RawcodeDecode,
+{
+    /// The record size
+    const SIZE: usize = {
+        assert!(N == T::SIZE, "Staging buffer size does not match the record size");
+        assert!(N > 0, "Cannot stream zero-sized records");
+        N
+    };
+
+    /// Creates a new stream decoder
+    pub const fn new() -> Self {
+        // Validate the staging buffer size at compile time
+        let _ = Self::SIZE;
+        Self { staging: Staging::new(), index: 0, skipped: 0, _record: PhantomData }
+    }
+
+    /// Pushes the next chunk of the stream and returns an iterator over all records that have been completed
+    ///
+    /// # Note
+    /// The chunk is consumed lazily by the returned iterator; if the iterator is dropped before it is exhausted, the
+    /// remaining records are skipped (see [Early Drop](StreamDecoder#early-drop)).
+    pub fn push<'a>(&'a mut self, chunk: &'a [u8]) -> StreamRecords<'a, T, N> {
+        StreamRecords { decoder: self, chunk }
+    }
+
+    /// The amount of bytes of the current incomplete record
+    pub const fn pending(&self) -> usize {
+        self.staging.len()
+    }
+    /// The amount of records that have been completed so far (including skipped records)
+    pub const fn records(&self) -> usize {
+        self.index
+    }
+    /// The amount of completed records that have been skipped because the records iterator was dropped early
+    pub const fn skipped(&self) -> usize {
+        self.skipped
+    }
+    /// Discards the current incomplete record (e.g. to resynchronize after a stream error)
+    pub fn reset(&mut self) {
+        self.staging.clear();
+    }
+}
+impl<T, const N: usize> Default for StreamDecoder<T, N>
+where
+    T: RawcodeDecode,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator over the records that have been completed by a chunk
+#[derive(Debug)]
+#[must_use = "the chunk is consumed by iterating over the records"]
+pub struct StreamRecords<'a, T, const N: usize> {
+    /// The stream decoder
+    decoder: &'a mut StreamDecoder<T, N>,
+    /// The remaining bytes of the chunk
+    chunk: &'a [u8],
+}
+impl<T, const N: usize> Iterator for StreamRecords<'_, T, N>
+where
+    T: RawcodeDecode,
+{
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Fill the staging buffer
+        let decoder = &mut *self.decoder;
+        if !decoder.staging.fill(&mut self.chunk) {
+            return None;
+        }
+
+        // Decode the record
+        let (index, offset) = (decoder.index, decoder.index * N);
+        let result = T::decode(decoder.staging.bytes());
+        decoder.staging.clear();
+        decoder.index += 1;
+        Some(result.map_err(|error| error.with_index(index).with_offset(offset)))
+    }
+}
+impl<T, const N: usize> Drop for StreamRecords<'_, T, N> {
+    fn drop(&mut self) {
+        // Skip the remaining records to keep the stream aligned
+        while self.decoder.staging.fill(&mut self.chunk) {
+            self.decoder.staging.clear();
+            self.decoder.index += 1;
+            self.decoder.skipped += 1;
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Implements the encoding of `Duration` and `SystemTime`
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::{marker::PhantomData, time::SystemTime};
+
+/// The amount of nanoseconds per second
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+// Implements rawcode for `Duration` as seconds (`u64`) followed by the subsecond nanoseconds (`u32`)
+impl RawcodeConstSize for Duration {
+    const SIZE: usize = u64::SIZE + u32::SIZE;
+}
+impl RawcodeDecode for Duration {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        // Decode the fields
+        let (secs, nanos): (u64, u32) = match buf.len() {
+            len if len > Self::SIZE => return Err(e!(TooLong { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(TooShort { expected: Self::SIZE, actual: len })),
+            _ => (u64::decode(&buf[..u64::SIZE])?, u32::decode(&buf[u64::SIZE..])?),
+        };
+
+        // Validate the nanoseconds
+        match nanos {
+            nanos if nanos >= NANOS_PER_SEC => Err(e!(Validation, "Invalid subsecond nanoseconds")),
+            nanos => Ok(Duration::new(secs, nanos)),
+        }
+    }
+}
+impl RawcodeEncode for Duration {
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        match buf.len() {
+            len if len > Self::SIZE => Err(e!(BufferTooLarge { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => Err(e!(BufferTooSmall { expected: Self::SIZE, actual: len })),
+            _ => {
+                let (secs, nanos) = buf.split_at_mut(u64::SIZE);
+                self.as_secs().encode(secs)?;
+                self.subsec_nanos().encode(nanos)
+            }
+        }
+    }
+}
+
+/// Converts `time` into the amount of units since the Unix epoch (rounded towards negative infinity)
+#[cfg(feature = "std")]
+fn to_units(time: SystemTime, nanos_per_unit: u128) -> i128 {
+    // Note: The casts are lossless since a `Duration` is at most `2^64 * 10^9 < 2^94` nanoseconds
+    match time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(since) => (since.as_nanos() / nanos_per_unit) as i128,
+        Err(before) => -(before.duration().as_nanos().div_ceil(nanos_per_unit) as i128),
+    }
+}
+/// Converts the amount of `units` since the Unix epoch into a system time
+#[cfg(feature = "std")]
+fn from_units(units: i128, nanos_per_unit: u128) -> Option<SystemTime> {
+    // Compute the offset to the epoch
+    let nanos = units.unsigned_abs().checked_mul(nanos_per_unit)?;
+    let secs = u64::try_from(nanos / u128::from(NANOS_PER_SEC)).ok()?;
+    let offset = Duration::new(secs, (nanos % u128::from(NANOS_PER_SEC)) as u32);
+
+    // Apply the offset
+    match units.is_negative() {
+        true => SystemTime::UNIX_EPOCH.checked_sub(offset),
+        false => SystemTime::UNIX_EPOCH.checked_add(offset),
+    }
+}
+
+/// Implements a wrapper that encodes a `SystemTime` as the amount of units since the Unix epoch
+#[cfg(feature = "std")]
+macro_rules! impl_unix_time {
+    ($(#[$meta:meta])* $type:ident($nanos_per_unit:expr)) => {
+        $(#[$meta])*
+        ///
+        /// # Encoding
+        /// The time is encoded as the wire integer `T` and rounded towards negative infinity. Pre-epoch times are
+        /// encoded as negative values; therefore they can only be encoded if `T` is signed, and encoding fails
+        /// otherwise.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $type<T> {
+            /// The underlying time
+            time: SystemTime,
+            /// The wire type
+            _wire: PhantomData<T>,
+        }
+        impl<T> $type<T> {
+            /// Creates a new wrapper
+            pub const fn new(time: SystemTime) -> Self {
+                Self { time, _wire: PhantomData }
+            }
+
+            /// The underlying time
+            pub const fn get(self) -> SystemTime {
+                self.time
+            }
+        }
+        impl<T> From<SystemTime> for $type<T> {
+            fn from(time: SystemTime) -> Self {
+                Self::new(time)
+            }
+        }
+        impl<T> From<$type<T>> for SystemTime {
+            fn from(time: $type<T>) -> Self {
+                time.time
+            }
+        }
+        impl<T> RawcodeConstSize for $type<T>
+        where
+            T: RawcodeConstSize,
+        {
+            const SIZE: usize = T::SIZE;
+        }
+        impl<T> RawcodeDecode for $type<T>
+        where
+            T: RawcodeDecode,
+            i128: TryFrom<T>,
+        {
+            fn decode(buf: &[u8]) -> Result<Self, Error> {
+                let units = i128::try_from(T::decode(buf)?).map_err(|_| e!(OutOfRange, "Timestamp is out of range"))?;
+                let time = from_units(units, $nanos_per_unit).ok_or(e!(OutOfRange, "Timestamp is out of range"))?;
+                Ok(Self::new(time))
+            }
+        }
+        impl<T> RawcodeEncode for $type<T>
+        where
+            T: RawcodeEncode + TryFrom<i128>,
+        {
+            fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+                let units = to_units(self.time, $nanos_per_unit);
+                let wire = T::try_from(units).map_err(|_| match units.is_negative() {
+                    true => e!(OutOfRange, "Pre-epoch timestamp does not fit into wire type"),
+                    false => e!(OutOfRange, "Timestamp does not fit into wire type"),
+                })?;
+                wire.encode(buf)
+            }
+        }
+    };
+}
+#[cfg(feature = "std")]
+impl_unix_time!(
+    /// A `SystemTime` that is encoded as seconds since the Unix epoch (e.g. `UnixSeconds<i64>`)
+    UnixSeconds(1_000_000_000)
+);
+#[cfg(feature = "std")]
+impl_unix_time!(
+    /// A `SystemTime` that is encoded as milliseconds since the Unix epoch (e.g. `UnixMillis<i64>`)
+    UnixMillis(1_000_000)
+);
+#[cfg(feature = "std")]
+impl_unix_time!(
+    /// A `SystemTime` that is encoded as nanoseconds since the Unix epoch (e.g. `UnixNanos<i64>`)
+    UnixNanos(1)
+);
@@ -19,7 +19,7 @@ macro_rules! impl_zero_sized {
             fn decode(buf: &[u8]) -> Result<Self, Error> {
                 match buf.len() {
                     Self::SIZE => Ok(Default::default()),
-                    _ => Err(e!("Encoded data is too long")),
+                    len => Err(e!(TooLong { expected: Self::SIZE, actual: len })),
                 }
             }
         }
@@ -27,7 +27,7 @@ macro_rules! impl_zero_sized {
             fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
                 match buf.len() {
                     Self::SIZE => Ok(()),
-                    _ => Err(e!("Target buffer is too large")),
+                    len => Err(e!(BufferTooLarge { expected: Self::SIZE, actual: len })),
                 }
             }
         }
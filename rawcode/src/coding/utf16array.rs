@@ -0,0 +1,157 @@
+//! Implements the encoding of UTF-16 arrays
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::{
+    char,
+    fmt::{self, Debug, Display, Formatter, Write},
+};
+
+/// A UTF-16 array of `UNITS` code units that always guarantees that it's contents are valid UTF-16
+///
+/// # Encoding
+/// The code units are encoded in **little-endian** representation (i.e. UTF-16LE). If `PADDED` is `true`, the string
+/// may be shorter than `UNITS` code units and is padded with NUL units; in this case the first NUL unit terminates the
+/// string and any non-NUL unit after the terminator is rejected.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Utf16Array<const UNITS: usize, const PADDED: bool = false> {
+    /// The UTF-16 code units
+    utf16_units: [u16; UNITS],
+}
+impl<const UNITS: usize, const PADDED: bool> Utf16Array<UNITS, PADDED> {
+    /// Creates a new `Utf16Array`
+    ///
+    /// # Panic
+    /// Panics if the literal is invalid UTF-16
+    pub const fn new(units: &[u16; UNITS]) -> Self {
+        match Self::from_array(*units) {
+            Some(this) => this,
+            None => panic!("Invalid string literal"),
+        }
+    }
+
+    /// Tries to create a new `Utf16Array` from a code unit array
+    const fn from_array(array: [u16; UNITS]) -> Option<Self> {
+        let (mut index, mut terminated) = (0, false);
+        while index < UNITS {
+            match array[index] {
+                0x0000 if terminated => (/* padding */),
+                _ if terminated => return None,
+                0x0000 if PADDED => terminated = true,
+                0xd800..=0xdbff if index + 1 < UNITS => match array[index + 1] {
+                    0xdc00..=0xdfff => index += 1,
+                    _ => return None,
+                },
+                0xd800..=0xdfff => return None,
+                _ => (/* all ok */),
+            }
+            index += 1;
+        }
+        Some(Self { utf16_units: array })
+    }
+
+    /// The content code units without padding
+    pub fn as_units(&self) -> &[u16] {
+        match PADDED {
+            true => self.utf16_units.split(|unit| *unit == 0).next().unwrap_or_default(),
+            false => &self.utf16_units,
+        }
+    }
+    /// An iterator over the decoded characters
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        // The `expect` cannot fail because we've already validated the string during construction
+        char::decode_utf16(self.as_units().iter().copied()).map(|char| char.expect("Array is invalid UTF-16?!"))
+    }
+}
+impl<const UNITS: usize, const PADDED: bool> Debug for Utf16Array<UNITS, PADDED> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("Utf16Array { utf16_units: \"")?;
+        self.chars().try_for_each(|char| write!(f, "{}", char.escape_debug()))?;
+        f.write_str("\" }")
+    }
+}
+impl<const UNITS: usize, const PADDED: bool> Display for Utf16Array<UNITS, PADDED> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.chars().try_for_each(|char| f.write_char(char))
+    }
+}
+impl<const UNITS: usize, const PADDED: bool> TryFrom<&str> for Utf16Array<UNITS, PADDED> {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        // Encode the string
+        let (mut utf16_units, mut len) = ([0; UNITS], 0);
+        for unit in value.encode_utf16() {
+            let slot = utf16_units.get_mut(len).ok_or(e!(InvalidLength, "Invalid string length"))?;
+            *slot = unit;
+            len += 1;
+        }
+
+        // Validate the length and the padding
+        match len {
+            len if len < UNITS && !PADDED => Err(e!(InvalidLength, "Invalid string length")),
+            _ if PADDED && value.contains('\0') => Err(e!(InvalidText, "String contains NUL characters")),
+            _ => Self::try_from(utf16_units),
+        }
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const UNITS: usize, const PADDED: bool> TryFrom<String> for Utf16Array<UNITS, PADDED> {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+impl<const UNITS: usize, const PADDED: bool> TryFrom<[u16; UNITS]> for Utf16Array<UNITS, PADDED> {
+    type Error = Error;
+
+    fn try_from(units: [u16; UNITS]) -> Result<Self, Self::Error> {
+        Self::from_array(units).ok_or(e!(InvalidText, "Array contains invalid UTF-16"))
+    }
+}
+impl<const UNITS: usize, const PADDED: bool> TryFrom<&[u16]> for Utf16Array<UNITS, PADDED> {
+    type Error = Error;
+
+    fn try_from(value: &[u16]) -> Result<Self, Self::Error> {
+        let units = <[u16; UNITS]>::try_from(value).map_err(|_| e!(InvalidLength, "Invalid slice length"))?;
+        Self::try_from(units)
+    }
+}
+impl<const UNITS: usize, const PADDED: bool> AsRef<[u16]> for Utf16Array<UNITS, PADDED> {
+    fn as_ref(&self) -> &[u16] {
+        self.as_units()
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const UNITS: usize, const PADDED: bool> From<Utf16Array<UNITS, PADDED>> for String {
+    fn from(array: Utf16Array<UNITS, PADDED>) -> Self {
+        array.chars().collect()
+    }
+}
+impl<const UNITS: usize, const PADDED: bool> From<Utf16Array<UNITS, PADDED>> for [u16; UNITS] {
+    fn from(array: Utf16Array<UNITS, PADDED>) -> Self {
+        array.utf16_units
+    }
+}
+impl<const UNITS: usize, const PADDED: bool> RawcodeConstSize for Utf16Array<UNITS, PADDED> {
+    const SIZE: usize = <[u16; UNITS]>::SIZE;
+}
+impl<const UNITS: usize, const PADDED: bool> RawcodeEncode for Utf16Array<UNITS, PADDED> {
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        self.utf16_units.encode(buf)
+    }
+}
+impl<const UNITS: usize, const PADDED: bool> RawcodeDecode for Utf16Array<UNITS, PADDED> {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        let units = <[u16; UNITS]>::decode(buf)?;
+        Self::try_from(units)
+    }
+}
+
+/// A UTF-16 array of up to `UNITS` code units that is padded with NUL units
+pub type PaddedUtf16Array<const UNITS: usize> = Utf16Array<UNITS, true>;
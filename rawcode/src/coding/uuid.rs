@@ -0,0 +1,184 @@
+//! Implements UUID/GUID coding
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+/// A UUID byte layout
+pub trait UuidLayout {
+    /// Converts between the canonical RFC 4122 byte order and the layout's byte order
+    ///
+    /// # Note
+    /// The conversion must be an involution (i.e. converting twice yields the original bytes).
+    fn convert(bytes: [u8; 16]) -> [u8; 16];
+}
+
+/// The RFC 4122 byte layout where all fields are stored in network byte order (i.e. **big-endian**)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rfc4122;
+impl UuidLayout for Rfc4122 {
+    fn convert(bytes: [u8; 16]) -> [u8; 16] {
+        bytes
+    }
+}
+
+/// The Microsoft GUID byte layout where the first three fields (`Data1`, `Data2` and `Data3`) are stored in
+/// **little-endian** and the remaining eight bytes (`Data4`) are stored as-is
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MixedEndian;
+impl UuidLayout for MixedEndian {
+    fn convert(mut bytes: [u8; 16]) -> [u8; 16] {
+        bytes[0..4].reverse();
+        bytes[4..6].reverse();
+        bytes[6..8].reverse();
+        bytes
+    }
+}
+
+/// The variant of a UUID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UuidVariant {
+    /// Reserved for NCS backward compatibility (`0b0xxx`)
+    Ncs,
+    /// The RFC 4122 variant (`0b10xx`)
+    Rfc4122,
+    /// Reserved for Microsoft backward compatibility (`0b110x`)
+    Microsoft,
+    /// Reserved for future definition (`0b111x`)
+    Future,
+}
+
+/// A 128 bit UUID that is encoded using the byte layout `L`
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Uuid16<L = Rfc4122> {
+    /// The UUID bytes in canonical RFC 4122 byte order
+    bytes: [u8; 16],
+    /// The byte layout
+    _layout: PhantomData<L>,
+}
+impl<L> Uuid16<L> {
+    /// The nil UUID (i.e. all bits are zero)
+    pub const NIL: Self = Self::from_bytes([0; 16]);
+
+    /// Creates a new UUID from it's bytes in canonical RFC 4122 byte order
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self { bytes, _layout: PhantomData }
+    }
+    /// The UUID bytes in canonical RFC 4122 byte order
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.bytes
+    }
+
+    /// The UUID version (i.e. the high nibble of the `time_hi_and_version` field)
+    pub const fn version(&self) -> u8 {
+        self.bytes[6] >> 4
+    }
+    /// The UUID variant
+    pub const fn variant(&self) -> UuidVariant {
+        match self.bytes[8] {
+            0x00..=0x7f => UuidVariant::Ncs,
+            0x80..=0xbf => UuidVariant::Rfc4122,
+            0xc0..=0xdf => UuidVariant::Microsoft,
+            0xe0..=0xff => UuidVariant::Future,
+        }
+    }
+
+    /// Converts the UUID into a UUID with another byte layout
+    pub const fn with_layout<M>(self) -> Uuid16<M> {
+        Uuid16::from_bytes(self.bytes)
+    }
+}
+impl<L> Default for Uuid16<L> {
+    fn default() -> Self {
+        Self::NIL
+    }
+}
+impl<L> Debug for Uuid16<L> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Uuid16({self})")
+    }
+}
+impl<L> Display for Uuid16<L> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (index, byte) in self.bytes.iter().enumerate() {
+            match index {
+                4 | 6 | 8 | 10 => write!(f, "-{byte:02x}")?,
+                _ => write!(f, "{byte:02x}")?,
+            }
+        }
+        Ok(())
+    }
+}
+impl<L> FromStr for Uuid16<L> {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        // Validate the hyphens
+        let string = string.as_bytes();
+        match string.len() {
+            36 if [8, 13, 18, 23].iter().all(|index| string[*index] == b'-') => (/* all ok */),
+            _ => return Err(e!(Validation, "Invalid UUID format")),
+        }
+
+        // Parse the hex digits
+        let mut bytes = [0; 16];
+        let mut digits = string.iter().filter(|char| **char != b'-');
+        for byte in bytes.iter_mut() {
+            let (high, low) = (digits.next(), digits.next());
+            let (Some(high), Some(low)) = (high.and_then(hex_digit), low.and_then(hex_digit)) else {
+                return Err(e!(Validation, "Invalid UUID format"));
+            };
+            *byte = (high << 4) | low;
+        }
+        Ok(Self::from_bytes(bytes))
+    }
+}
+impl<L> From<[u8; 16]> for Uuid16<L> {
+    fn from(bytes: [u8; 16]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+impl<L> From<Uuid16<L>> for [u8; 16] {
+    fn from(uuid: Uuid16<L>) -> Self {
+        uuid.bytes
+    }
+}
+impl<L> RawcodeConstSize for Uuid16<L> {
+    const SIZE: usize = 16;
+}
+impl<L> RawcodeDecode for Uuid16<L>
+where
+    L: UuidLayout,
+{
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        let bytes = <[u8; 16]>::decode(buf)?;
+        Ok(Self::from_bytes(L::convert(bytes)))
+    }
+}
+impl<L> RawcodeEncode for Uuid16<L>
+where
+    L: UuidLayout,
+{
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        L::convert(self.bytes).encode(buf)
+    }
+}
+
+/// Parses a single hex digit
+fn hex_digit(char: &u8) -> Option<u8> {
+    match char {
+        b'0'..=b'9' => Some(char - b'0'),
+        b'a'..=b'f' => Some(char - b'a' + 10),
+        b'A'..=b'F' => Some(char - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// A Microsoft GUID (i.e. a UUID that is encoded using the mixed-endian byte layout)
+pub type Guid = Uuid16<MixedEndian>;
@@ -1,31 +1,365 @@
 //! Implements the crate's error type
 
-use core::fmt::{self, Display, Formatter};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    hash::{Hash, Hasher},
+    panic::Location,
+    str,
+};
 
 /// Creates a new variant
 #[macro_export]
 #[doc(hidden)]
 macro_rules! e {
-    ($message:expr) => {{
-        $crate::error::Error::new($message, file!(), line!())
+    ($kind:ident $({ $($fields:tt)* })?) => {{
+        let kind = $crate::error::ErrorKind::$kind $({ $($fields)* })?;
+        $crate::error::Error::new(kind, kind.default_message())
     }};
+    ($kind:ident $({ $($fields:tt)* })?, $message:expr) => {{
+        let kind = $crate::error::ErrorKind::$kind $({ $($fields)* })?;
+        $crate::error::Error::new(kind, $message)
+    }};
+}
+
+/// The kind of an error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The encoded data is shorter than the expected size
+    TooShort {
+        /// The expected amount of bytes
+        expected: usize,
+        /// The actual amount of bytes
+        actual: usize,
+    },
+    /// The encoded data is longer than the expected size
+    TooLong {
+        /// The expected amount of bytes
+        expected: usize,
+        /// The actual amount of bytes
+        actual: usize,
+    },
+    /// The target buffer is smaller than the expected size
+    BufferTooSmall {
+        /// The expected amount of bytes
+        expected: usize,
+        /// The actual amount of bytes
+        actual: usize,
+    },
+    /// The target buffer is larger than the expected size
+    BufferTooLarge {
+        /// The expected amount of bytes
+        expected: usize,
+        /// The actual amount of bytes
+        actual: usize,
+    },
+    /// A slice or string has an invalid length for the conversion into a fixed-size type
+    InvalidLength,
+    /// An encoded boolean is neither `0x00` nor `0xFF`
+    InvalidBool,
+    /// A string is not valid UTF-8
+    InvalidUtf8,
+    /// A string contains characters or code units that are not allowed by it's character set or format
+    InvalidText,
+    /// An encoded tag (e.g. an address family) is unknown
+    InvalidTag,
+    /// Padding or otherwise unused bits are not zero-filled
+    InvalidPadding,
+    /// A value is outside of the representable or allowed range
+    OutOfRange,
+    /// A value failed a semantic validation (e.g. an invalid calendar date)
+    Validation,
+    /// A frame does not start with the expected sync word
+    InvalidSync,
+    /// A frame's checksum does not match it's contents
+    InvalidChecksum,
+    /// A byte-stuffed frame contains an unexpected delimiter or escape sequence
+    InvalidStuffing,
+    /// An I/O operation failed (the underlying `std::io::Error` is available as source)
+    Io,
+    /// A stream ended within a record
+    TruncatedRecord {
+        /// The expected amount of bytes
+        expected: usize,
+        /// The actual amount of bytes
+        actual: usize,
+    },
+    /// A user-defined error (see [`Error::custom`])
+    Custom {
+        /// The optional numeric detail (e.g. the offending value)
+        detail: Option<u64>,
+    },
+}
+impl ErrorKind {
+    /// The default error message for the kind
+    #[doc(hidden)]
+    pub const fn default_message(&self) -> &'static str {
+        match self {
+            Self::TooShort { .. } => "Encoded data is too short",
+            Self::TooLong { .. } => "Encoded data is too long",
+            Self::BufferTooSmall { .. } => "Target buffer is too small",
+            Self::BufferTooLarge { .. } => "Target buffer is too large",
+            Self::InvalidLength => "Invalid length",
+            Self::InvalidBool => "Invalid boolean",
+            Self::InvalidUtf8 => "Invalid UTF-8",
+            Self::InvalidText => "Invalid text",
+            Self::InvalidTag => "Invalid tag",
+            Self::InvalidPadding => "Invalid padding",
+            Self::OutOfRange => "Value is out of range",
+            Self::Validation => "Validation failed",
+            Self::InvalidSync => "Invalid sync word",
+            Self::InvalidChecksum => "Checksum mismatch",
+            Self::InvalidStuffing => "Invalid byte stuffing",
+            Self::Io => "I/O error",
+            Self::TruncatedRecord { .. } => "Truncated record",
+            Self::Custom { .. } => "Custom error",
+        }
+    }
+}
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.default_message())?;
+
+        // Append the details if any
+        match self {
+            Self::TooShort { expected, actual }
+            | Self::TooLong { expected, actual }
+            | Self::BufferTooSmall { expected, actual }
+            | Self::BufferTooLarge { expected, actual }
+            | Self::TruncatedRecord { expected, actual } => write!(f, " (expected {expected} bytes, got {actual})"),
+            Self::Custom { detail: Some(detail) } => write!(f, " (detail {detail})"),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The field path context of an error
+///
+/// # Note
+/// The path is stored right-aligned in a fixed-size buffer so that segments can be prepended without further allocation
+/// while the error propagates outwards; segments that do not fit anymore are dropped and the path is marked as
+/// truncated. The capacity is the same for all feature sets, so that the reported paths do not depend on the features.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Context {
+    /// The name of the outermost type
+    root: Option<&'static str>,
+    /// The path segments (e.g. `.list[3].flag`)
+    path: [u8; Self::PATH_CAPACITY],
+    /// The start of the path within the buffer
+    path_start: u8,
+    /// Whether outer path segments have been dropped or not
+    truncated: bool,
+    /// The byte offset within the input or target buffer
+    offset: usize,
+}
+impl Context {
+    /// The path buffer capacity (together with the start and truncation marker, the path occupies 32 bytes)
+    const PATH_CAPACITY: usize = 30;
+    /// An empty context
+    #[cfg(feature = "alloc")]
+    const EMPTY: Self = Self::new();
+
+    /// Creates a new empty context
+    const fn new() -> Self {
+        Self {
+            root: None,
+            path: [0; Self::PATH_CAPACITY],
+            path_start: Self::PATH_CAPACITY as u8,
+            truncated: false,
+            offset: 0,
+        }
+    }
+
+    /// The path segments
+    fn path(&self) -> &str {
+        let path = &self.path[self.path_start as usize..];
+        str::from_utf8(path).expect("Path is not valid UTF-8?!")
+    }
+
+    /// Prepends the concatenation of `parts` as a single segment to the path
+    ///
+    /// # Note
+    /// Once a segment has been dropped, all further outer segments are dropped too so that the path stays contiguous.
+    fn prepend(&mut self, parts: &[&[u8]]) {
+        // Ensure that the path is not truncated and that the segment fits into the buffer
+        if self.truncated {
+            return;
+        }
+        let len: usize = parts.iter().map(|part| part.len()).sum();
+        let Some(start) = (self.path_start as usize).checked_sub(len) else {
+            self.truncated = true;
+            return;
+        };
+
+        // Copy the parts
+        let mut pos = start;
+        for part in parts {
+            self.path[pos..pos + part.len()].copy_from_slice(part);
+            pos += part.len();
+        }
+        self.path_start = start as u8;
+    }
+}
+
+/// The error context which is stored out of line if the `alloc` feature is enabled
+///
+/// # Note
+/// Errors are returned on every failure path, so the context is only allocated once it is set; without the `alloc`
+/// feature, the context is stored inline which adds about 50 bytes to the size of `Error`.
+struct ContextSlot {
+    /// The boxed context if any
+    #[cfg(feature = "alloc")]
+    context: Option<Box<Context>>,
+    /// The inline context
+    #[cfg(not(feature = "alloc"))]
+    context: Context,
+}
+impl ContextSlot {
+    /// Creates a new empty context slot
+    const fn new() -> Self {
+        #[cfg(feature = "alloc")]
+        return Self { context: None };
+        #[cfg(not(feature = "alloc"))]
+        return Self { context: Context::new() };
+    }
+
+    /// The context
+    fn get(&self) -> &Context {
+        #[cfg(feature = "alloc")]
+        return self.context.as_deref().unwrap_or(&Context::EMPTY);
+        #[cfg(not(feature = "alloc"))]
+        return &self.context;
+    }
+    /// The context for modification
+    fn get_mut(&mut self) -> &mut Context {
+        #[cfg(feature = "alloc")]
+        return self.context.get_or_insert_with(|| Box::new(Context::new()));
+        #[cfg(not(feature = "alloc"))]
+        return &mut self.context;
+    }
+}
+impl fmt::Debug for ContextSlot {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.get(), f)
+    }
+}
+impl PartialEq for ContextSlot {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+impl Eq for ContextSlot {
+    /* No members to implement */
+}
+impl PartialOrd for ContextSlot {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for ContextSlot {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get().cmp(other.get())
+    }
+}
+impl Hash for ContextSlot {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get().hash(state);
+    }
 }
 
 /// A error type
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Error {
+    /// The error kind
+    kind: ErrorKind,
     /// The error message
     message: &'static str,
-    /// The file where the error originated
-    file: &'static str,
-    /// The line where the error originated
-    line: u32,
+    /// The location where the error originated
+    location: &'static Location<'static>,
+    /// The field path and offset context
+    context: ContextSlot,
+    /// The underlying error if any
+    #[cfg(feature = "std")]
+    source: Source,
 }
 impl Error {
     /// Creates a new error
     #[doc(hidden)]
-    pub const fn new(message: &'static str, file: &'static str, line: u32) -> Self {
-        Self { message, file, line }
+    #[track_caller]
+    pub const fn new(kind: ErrorKind, message: &'static str) -> Self {
+        Self {
+            kind,
+            message,
+            location: Location::caller(),
+            context: ContextSlot::new(),
+            #[cfg(feature = "std")]
+            source: Source(None),
+        }
+    }
+
+    /// Creates a new user-defined error for custom trait implementations
+    #[track_caller]
+    pub const fn custom(message: &'static str) -> Self {
+        Self::new(ErrorKind::Custom { detail: None }, message)
+    }
+    /// Creates a new user-defined error with a numeric detail (e.g. the offending value)
+    #[track_caller]
+    pub const fn custom_with_detail(message: &'static str, detail: u64) -> Self {
+        Self::new(ErrorKind::Custom { detail: Some(detail) }, message)
+    }
+    /// Sets the underlying error that caused this error (see [`std::error::Error::source`])
+    ///
+    /// # Note
+    /// The source error is ignored if errors are compared or hashed.
+    #[cfg(feature = "std")]
+    pub fn with_source<E>(mut self, source: E) -> Self
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        self.source = Source(Some(source.into()));
+        self
+    }
+
+    /// Sets the name of the outermost type (e.g. `Named`) that has been de-/encoded
+    pub fn with_root(mut self, name: &'static str) -> Self {
+        self.context.get_mut().root = Some(name);
+        self
+    }
+    /// Prepends a field segment (e.g. `.flag`) to the field path
+    pub fn with_field(mut self, name: &'static str) -> Self {
+        self.context.get_mut().prepend(&[b".", name.as_bytes()]);
+        self
+    }
+    /// Prepends an index segment (e.g. `[3]`) to the field path
+    pub fn with_index(mut self, mut index: usize) -> Self {
+        // Format the index
+        let (mut digits, mut start) = ([0; 20], 20);
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (index % 10) as u8;
+            index /= 10;
+            if index == 0 {
+                break;
+            }
+        }
+
+        // Prepend the segment
+        self.context.get_mut().prepend(&[b"[", &digits[start..], b"]"]);
+        self
+    }
+    /// Adds `offset` to the byte offset (i.e. makes the offset relative to an outer buffer)
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        let context = self.context.get_mut();
+        context.offset = context.offset.saturating_add(offset);
+        self
+    }
+
+    /// The error kind
+    pub const fn kind(&self) -> ErrorKind {
+        self.kind
     }
 
     /// The error message
@@ -35,15 +369,89 @@ impl Error {
 
     /// The error location
     pub const fn location(&self) -> (&'static str, u32) {
-        (self.file, self.line)
+        (self.location.file(), self.location.line())
+    }
+
+    /// The name of the outermost type that has been de-/encoded if any
+    pub fn root(&self) -> Option<&'static str> {
+        self.context.get().root
+    }
+    /// The field path relative to the root type (e.g. `.list[3].flag`)
+    ///
+    /// # Note
+    /// The path is limited to 30 bytes; if it becomes longer, outer segments are dropped. Use
+    /// [`Self::is_path_truncated`] to check for truncation.
+    pub fn path(&self) -> &str {
+        self.context.get().path()
+    }
+    /// Whether outer path segments have been dropped or not
+    pub fn is_path_truncated(&self) -> bool {
+        self.context.get().truncated
+    }
+    /// The absolute byte offset of the erroneous value within the input or target buffer
+    pub fn offset(&self) -> usize {
+        self.context.get().offset
     }
 }
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{} at {}:{}", self.message, self.file, self.line)
+        write!(f, "{} at {}:{}", self.message, self.location.file(), self.location.line())?;
+
+        // Append the context if any
+        let context = self.context.get();
+        let (root, path) = (context.root.unwrap_or_default(), context.path());
+        let truncated = match context.truncated {
+            true => "...",
+            false => "",
+        };
+        match (root, path) {
+            ("", "") => Ok(()),
+            _ => write!(f, " (in `{root}{truncated}{path}` at byte offset {})", context.offset),
+        }
     }
 }
 #[cfg(feature = "std")]
 impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        let source = self.source.0.as_deref()?;
+        Some(source)
+    }
+}
+
+/// An optional boxed source error that is ignored during comparisons and hashing
+#[cfg(feature = "std")]
+struct Source(Option<Box<dyn std::error::Error + Send + Sync>>);
+#[cfg(feature = "std")]
+impl fmt::Debug for Source {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+#[cfg(feature = "std")]
+impl PartialEq for Source {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+#[cfg(feature = "std")]
+impl Eq for Source {
     /* No members to implement */
 }
+#[cfg(feature = "std")]
+impl PartialOrd for Source {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+#[cfg(feature = "std")]
+impl Ord for Source {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+#[cfg(feature = "std")]
+impl Hash for Source {
+    fn hash<H: Hasher>(&self, _state: &mut H) {
+        /* Nothing to hash */
+    }
+}
@@ -0,0 +1,98 @@
+//! Implements Consistent Overhead Byte Stuffing (COBS)
+
+use crate::{
+    error::Error,
+    framing::stuffing::{sealed::Codec, ByteStuffing},
+};
+
+/// Consistent Overhead Byte Stuffing (COBS) which removes all `0x00` bytes from the data so that `0x00` can be used as
+/// frame delimiter
+///
+/// # Encoding
+/// The data is split into blocks at every `0x00` byte and after 254 non-zero bytes; every block is encoded as code byte
+/// (i.e. the amount of non-zero bytes + 1) followed by it's non-zero bytes, and the frame is terminated by `0x00`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cobs;
+impl Cobs {
+    /// The frame delimiter
+    pub const DELIMITER: u8 = 0x00;
+
+    /// The worst-case frame length (including the delimiter) for `len` bytes of data (e.g. `T::SIZE`)
+    pub const fn max_encoded_len(len: usize) -> usize {
+        len + len / 254 + 2
+    }
+}
+impl ByteStuffing for Cobs {
+    fn max_encoded_len(len: usize) -> usize {
+        // Use the const inherent function
+        Cobs::max_encoded_len(len)
+    }
+}
+impl Codec for Cobs {
+    fn encode_in_place(buf: &mut [u8], start: usize) -> usize {
+        let (mut code_pos, mut pos, mut code) = (0, 1, 1);
+        for index in start..buf.len() {
+            // Append the byte to the current block
+            let byte = buf[index];
+            if byte != 0 {
+                buf[pos] = byte;
+                pos += 1;
+                code += 1;
+            }
+
+            // Finish the block at zero bytes or if it is full
+            let is_full = code == 0xFF && index + 1 < buf.len();
+            if byte == 0 || is_full {
+                buf[code_pos] = code;
+                (code_pos, pos, code) = (pos, pos + 1, 1);
+            }
+        }
+
+        // Finish the last block and append the delimiter
+        buf[code_pos] = code;
+        buf[pos] = Self::DELIMITER;
+        pos + 1
+    }
+    fn decode_with<R, W>(len: usize, read: R, mut write: W) -> Result<usize, Error>
+    where
+        R: Fn(usize) -> u8,
+        W: FnMut(usize, u8) -> Result<(), Error>,
+    {
+        // Strip the delimiter
+        let len = match len {
+            len if len > 0 && read(len - 1) == Self::DELIMITER => len - 1,
+            len => len,
+        };
+
+        // Decode the blocks
+        let (mut index, mut pos) = (0, 0);
+        while index < len {
+            // Read the code byte
+            let code = match read(index) {
+                Self::DELIMITER => return Err(e!(InvalidStuffing, "Unexpected delimiter")),
+                code => code,
+            };
+            let end = index + code as usize;
+            if end > len {
+                return Err(e!(InvalidStuffing, "Truncated block"));
+            }
+
+            // Copy the block
+            for index in index + 1..end {
+                match read(index) {
+                    Self::DELIMITER => return Err(e!(InvalidStuffing, "Unexpected delimiter")),
+                    byte => write(pos, byte)?,
+                }
+                pos += 1;
+            }
+
+            // Restore the zero byte between two blocks unless the block was full
+            index = end;
+            if code < 0xFF && index < len {
+                write(pos, 0)?;
+                pos += 1;
+            }
+        }
+        Ok(pos)
+    }
+}
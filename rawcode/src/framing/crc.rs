@@ -0,0 +1,97 @@
+//! Implements the frame checksums
+
+use crate::coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode};
+
+/// A frame checksum algorithm
+pub trait Checksum {
+    /// The checksum value
+    type Value: RawcodeConstSize + RawcodeDecode + RawcodeEncode + Copy + Eq;
+
+    /// Computes the checksum of `data`
+    fn checksum(data: &[u8]) -> Self::Value;
+}
+
+/// CRC-16/CCITT-FALSE (polynomial `0x1021`, initial value `0xFFFF`, not reflected, no final XOR)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Crc16;
+impl Crc16 {
+    /// The lookup table
+    const TABLE: [u16; 256] = {
+        let (mut table, mut index) = ([0; 256], 0);
+        while index < 256 {
+            // Compute the remainder of the byte
+            let (mut crc, mut bit) = ((index as u16) << 8, 0);
+            while bit < 8 {
+                crc = match crc & 0x8000 {
+                    0 => crc << 1,
+                    _ => (crc << 1) ^ 0x1021,
+                };
+                bit += 1;
+            }
+
+            table[index] = crc;
+            index += 1;
+        }
+        table
+    };
+
+    /// Computes the checksum of `data`
+    pub const fn compute(data: &[u8]) -> u16 {
+        let (mut crc, mut index) = (0xFFFF_u16, 0);
+        while index < data.len() {
+            crc = (crc << 8) ^ Self::TABLE[((crc >> 8) as u8 ^ data[index]) as usize];
+            index += 1;
+        }
+        crc
+    }
+}
+impl Checksum for Crc16 {
+    type Value = u16;
+
+    fn checksum(data: &[u8]) -> Self::Value {
+        Self::compute(data)
+    }
+}
+
+/// CRC-32/ISO-HDLC as used by e.g. Ethernet and ZIP (polynomial `0x04C11DB7`, initial value `0xFFFFFFFF`, reflected,
+/// final XOR `0xFFFFFFFF`)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Crc32;
+impl Crc32 {
+    /// The lookup table
+    const TABLE: [u32; 256] = {
+        let (mut table, mut index) = ([0; 256], 0);
+        while index < 256 {
+            // Compute the remainder of the byte
+            let (mut crc, mut bit) = (index as u32, 0);
+            while bit < 8 {
+                crc = match crc & 1 {
+                    0 => crc >> 1,
+                    _ => (crc >> 1) ^ 0xEDB8_8320,
+                };
+                bit += 1;
+            }
+
+            table[index] = crc;
+            index += 1;
+        }
+        table
+    };
+
+    /// Computes the checksum of `data`
+    pub const fn compute(data: &[u8]) -> u32 {
+        let (mut crc, mut index) = (0xFFFF_FFFF_u32, 0);
+        while index < data.len() {
+            crc = (crc >> 8) ^ Self::TABLE[(crc as u8 ^ data[index]) as usize];
+            index += 1;
+        }
+        !crc
+    }
+}
+impl Checksum for Crc32 {
+    type Value = u32;
+
+    fn checksum(data: &[u8]) -> Self::Value {
+        Self::compute(data)
+    }
+}
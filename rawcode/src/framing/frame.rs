@@ -0,0 +1,101 @@
+//! Implements the frame type
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, RawcodeEncode},
+    error::Error,
+    framing::crc::{Checksum, Crc32},
+};
+use core::marker::PhantomData;
+
+/// A frame that wraps a `T` payload
+///
+/// # Encoding
+/// The frame is encoded as sync word (`u16`, i.e. **little-endian**), the payload `T` and the checksum `C` of the
+/// encoded payload (i.e. `u16` for `Crc16` and `u32` for `Crc32`). During decoding, frames with an invalid sync word
+/// (`ErrorKind::InvalidSync`) or checksum (`ErrorKind::InvalidChecksum`) are rejected before the payload is decoded.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Frame<T, C = Crc32, const SYNC: u16 = 0x55AA> {
+    /// The payload
+    payload: T,
+    /// The checksum algorithm
+    _checksum: PhantomData<C>,
+}
+impl<T, C, const SYNC: u16> Frame<T, C, SYNC> {
+    /// The sync word
+    pub const SYNC: u16 = SYNC;
+
+    /// Creates a new frame
+    pub const fn new(payload: T) -> Self {
+        Self { payload, _checksum: PhantomData }
+    }
+
+    /// The payload
+    pub const fn payload(&self) -> &T {
+        &self.payload
+    }
+    /// Returns the payload
+    pub fn into_payload(self) -> T {
+        self.payload
+    }
+}
+impl<T, C, const SYNC: u16> From<T> for Frame<T, C, SYNC> {
+    fn from(payload: T) -> Self {
+        Self::new(payload)
+    }
+}
+impl<T, C, const SYNC: u16> RawcodeConstSize for Frame<T, C, SYNC>
+where
+    T: RawcodeConstSize,
+    C: Checksum,
+{
+    const SIZE: usize = u16::SIZE + T::SIZE + C::Value::SIZE;
+}
+impl<T, C, const SYNC: u16> RawcodeDecode for Frame<T, C, SYNC>
+where
+    T: RawcodeDecode,
+    C: Checksum,
+{
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        // Validate the input length
+        match buf.len() {
+            len if len > Self::SIZE => return Err(e!(TooLong { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(TooShort { expected: Self::SIZE, actual: len })),
+            _ => (/* all ok */),
+        }
+
+        // Validate the sync word and the checksum
+        let (sync, buf) = buf.split_at(u16::SIZE);
+        let (payload, checksum) = buf.split_at(T::SIZE);
+        if u16::decode(sync)? != SYNC {
+            return Err(e!(InvalidSync));
+        }
+        if C::Value::decode(checksum)? != C::checksum(payload) {
+            return Err(e!(InvalidChecksum).with_offset(u16::SIZE + T::SIZE));
+        }
+
+        // Decode the payload
+        let payload = T::decode(payload).map_err(|error| error.with_offset(u16::SIZE))?;
+        Ok(Self::new(payload))
+    }
+}
+impl<T, C, const SYNC: u16> RawcodeEncode for Frame<T, C, SYNC>
+where
+    T: RawcodeEncode,
+    C: Checksum,
+{
+    fn encode(&self, buf: &mut [u8]) -> Result<(), Error> {
+        // Validate the buffer length
+        match buf.len() {
+            len if len > Self::SIZE => return Err(e!(BufferTooLarge { expected: Self::SIZE, actual: len })),
+            len if len < Self::SIZE => return Err(e!(BufferTooSmall { expected: Self::SIZE, actual: len })),
+            _ => (/* all ok */),
+        }
+
+        // Encode the sync word, the payload and the checksum
+        let (sync, buf) = buf.split_at_mut(u16::SIZE);
+        let (payload, checksum) = buf.split_at_mut(T::SIZE);
+        SYNC.encode(sync)?;
+        self.payload.encode(payload).map_err(|error| error.with_offset(u16::SIZE))?;
+        C::checksum(payload).encode(checksum)
+    }
+}
@@ -0,0 +1,17 @@
+//! Implements framing of rawcode types for unreliable byte streams (e.g. UART links)
+
+mod cobs;
+mod crc;
+mod frame;
+mod receiver;
+mod slip;
+mod stuffing;
+
+pub use crate::framing::{
+    cobs::Cobs,
+    crc::{Checksum, Crc16, Crc32},
+    frame::Frame,
+    receiver::{FrameReceiver, FrameRecords, FrameStats},
+    slip::Slip,
+    stuffing::ByteStuffing,
+};
@@ -0,0 +1,204 @@
+//! Implements a resynchronizing frame receiver
+
+use crate::{
+    coding::{RawcodeConstSize, RawcodeDecode, Staging},
+    error::{Error, ErrorKind},
+    framing::{
+        crc::{Checksum, Crc32},
+        frame::Frame,
+    },
+};
+use core::marker::PhantomData;
+
+/// The statistics of a frame receiver
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FrameStats {
+    /// The amount of received frames with a valid checksum
+    frames: usize,
+    /// The amount of discarded frames with an invalid checksum
+    bad_frames: usize,
+    /// The amount of bytes that have been skipped during resynchronization
+    dropped_bytes: usize,
+    /// The amount of received frames that have been skipped because the payload iterator was dropped early
+    skipped_frames: usize,
+}
+impl FrameStats {
+    /// The amount of received frames with a valid checksum
+    pub const fn frames(&self) -> usize {
+        self.frames
+    }
+    /// The amount of discarded frames with an invalid checksum
+    pub const fn bad_frames(&self) -> usize {
+        self.bad_frames
+    }
+    /// The amount of bytes that have been skipped during resynchronization
+    pub const fn dropped_bytes(&self) -> usize {
+        self.dropped_bytes
+    }
+    /// The amount of received frames that have been skipped because the payload iterator was dropped early
+    ///
+    /// # Note
+    /// Skipped frames are also counted as received frames.
+    pub const fn skipped_frames(&self) -> usize {
+        self.skipped_frames
+    }
+}
+
+/// A receiver that scans a byte stream for `Frame<T, C, SYNC>`s and yields their payloads
+///
+/// # Note
+/// `N` is the size of the internal staging buffer and must be equal to `Frame::<T, C, SYNC>::SIZE`; this is checked at
+/// compile time.
+///
+/// # Resynchronization
+/// Bytes before the next sync word are skipped. If a frame has an invalid checksum, only it's first byte is skipped and
+/// the receiver scans for the next sync word, so that a valid frame is found again even if the sync word also occurred
+/// within a payload. Frames with an invalid checksum are not yielded but counted (see [`FrameStats`]); payloads that
+/// fail to decode despite a valid checksum are yielded as error.
+///
+/// # Early Drop
+/// The chunk is always consumed completely so that the receiver stays in sync. If the payload iterator is dropped
+/// before it is exhausted, the frames that are completed by the rest of the chunk are skipped and counted (see
+/// [`FrameStats::skipped_frames`]), so that lost frames can be detected.
+#[derive(Debug, Clone)]
+pub struct FrameReceiver<T, const N: usize, C = Crc32, const SYNC: u16 = 0x55AA> {
+    /// The staging buffer for the current frame
+    staging: Staging<N>,
+    /// The receiver statistics
+    stats: FrameStats,
+    /// The frame type
+    _frame: PhantomData<Frame<T, C, SYNC>>,
+}
+impl<T, const N: usize, C, const SYNC: u16> FrameReceiver<T, N, C, SYNC>
+where
+    T: RawcodeDecode,
+    C: Checksum,
+{
+    /// The frame size
+    const SIZE: usize = {
+        assert!(N == Frame::<T, C, SYNC>::SIZE, "Staging buffer size does not match the frame size");
+        N
+    };
+
+    /// Creates a new frame receiver
+    pub const fn new() -> Self {
+        // Validate the staging buffer size at compile time
+        let _ = Self::SIZE;
+        Self {
+            staging: Staging::new(),
+            stats: FrameStats { frames: 0, bad_frames: 0, dropped_bytes: 0, skipped_frames: 0 },
+            _frame: PhantomData,
+        }
+    }
+
+    /// Pushes the next chunk of the stream and returns an iterator over the payloads of all frames that have been
+    /// completed
+    ///
+    /// # Note
+    /// The chunk is consumed lazily by the returned iterator; if the iterator is dropped before it is exhausted, the
+    /// remaining frames are skipped (see [Early Drop](FrameReceiver#early-drop)).
+    pub fn push<'a>(&'a mut self, chunk: &'a [u8]) -> FrameRecords<'a, T, N, C, SYNC> {
+        FrameRecords { receiver: self, chunk }
+    }
+
+    /// The amount of bytes of the current incomplete frame
+    pub const fn pending(&self) -> usize {
+        self.staging.len()
+    }
+    /// The receiver statistics
+    pub const fn stats(&self) -> FrameStats {
+        self.stats
+    }
+    /// Discards the current incomplete frame
+    pub fn reset(&mut self) {
+        self.drop_bytes(self.staging.len());
+    }
+
+    /// Drops the first `len` bytes of the staging buffer
+    fn drop_bytes(&mut self, len: usize) {
+        self.staging.consume(len);
+        self.stats.dropped_bytes += len;
+    }
+    /// The position of the first (possibly incomplete) sync word within the staging buffer
+    fn find_sync(&self) -> usize {
+        let [first, second] = SYNC.to_le_bytes();
+        let buf = self.staging.bytes();
+        (0..buf.len())
+            .find(|&pos| buf[pos] == first && buf.get(pos + 1).map_or(true, |&byte| byte == second))
+            .unwrap_or(buf.len())
+    }
+}
+impl<T, const N: usize, C, const SYNC: u16> Default for FrameReceiver<T, N, C, SYNC>
+where
+    T: RawcodeDecode,
+    C: Checksum,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator over the payloads of the frames that have been completed by a chunk
+#[derive(Debug)]
+#[must_use = "the chunk is consumed by iterating over the payloads"]
+pub struct FrameRecords<'a, T, const N: usize, C, const SYNC: u16>
+where
+    T: RawcodeDecode,
+    C: Checksum,
+{
+    /// The frame receiver
+    receiver: &'a mut FrameReceiver<T, N, C, SYNC>,
+    /// The remaining bytes of the chunk
+    chunk: &'a [u8],
+}
+impl<T, const N: usize, C, const SYNC: u16> Iterator for FrameRecords<'_, T, N, C, SYNC>
+where
+    T: RawcodeDecode,
+    C: Checksum,
+{
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let receiver = &mut *self.receiver;
+        loop {
+            // Fill the staging buffer
+            let is_full = receiver.staging.fill(&mut self.chunk);
+
+            // Skip all bytes before the next sync word
+            match receiver.find_sync() {
+                0 => (/* all ok */),
+                skipped => {
+                    receiver.drop_bytes(skipped);
+                    continue;
+                }
+            }
+
+            // Decode the frame if it is complete
+            if !is_full {
+                return None;
+            }
+            match Frame::<T, C, SYNC>::decode(receiver.staging.bytes()) {
+                Err(error) if error.kind() == ErrorKind::InvalidChecksum => {
+                    receiver.stats.bad_frames += 1;
+                    receiver.drop_bytes(1);
+                }
+                result => {
+                    receiver.staging.clear();
+                    receiver.stats.frames += 1;
+                    return Some(result.map(Frame::into_payload));
+                }
+            }
+        }
+    }
+}
+impl<T, const N: usize, C, const SYNC: u16> Drop for FrameRecords<'_, T, N, C, SYNC>
+where
+    T: RawcodeDecode,
+    C: Checksum,
+{
+    fn drop(&mut self) {
+        // Skip the remaining frames to keep the receiver in sync
+        let skipped = self.by_ref().count();
+        self.receiver.stats.skipped_frames += skipped;
+    }
+}
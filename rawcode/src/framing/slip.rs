@@ -0,0 +1,96 @@
+//! Implements the Serial Line Internet Protocol (SLIP) byte stuffing
+
+use crate::{
+    error::Error,
+    framing::stuffing::{sealed::Codec, ByteStuffing},
+};
+
+/// SLIP byte stuffing (RFC 1055) which escapes all `END` bytes within the data so that `END` can be used as frame
+/// delimiter
+///
+/// # Encoding
+/// The frame is encoded as `END`, the data where `END` is replaced by `ESC ESC_END` and `ESC` is replaced by
+/// `ESC ESC_ESC`, and a terminating `END`. The leading `END` flushes any line noise and is optional during decoding.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Slip;
+impl Slip {
+    /// The frame delimiter
+    pub const END: u8 = 0xC0;
+    /// The escape byte
+    pub const ESC: u8 = 0xDB;
+    /// The escaped `END` byte
+    pub const ESC_END: u8 = 0xDC;
+    /// The escaped `ESC` byte
+    pub const ESC_ESC: u8 = 0xDD;
+
+    /// The worst-case frame length (including the delimiters) for `len` bytes of data (e.g. `T::SIZE`)
+    pub const fn max_encoded_len(len: usize) -> usize {
+        2 * len + 2
+    }
+}
+impl ByteStuffing for Slip {
+    fn max_encoded_len(len: usize) -> usize {
+        // Use the const inherent function
+        Slip::max_encoded_len(len)
+    }
+}
+impl Codec for Slip {
+    fn encode_in_place(buf: &mut [u8], start: usize) -> usize {
+        buf[0] = Self::END;
+        let mut pos = 1;
+        for index in start..buf.len() {
+            // Escape the byte if necessary
+            let escaped = match buf[index] {
+                Self::END => [Self::ESC, Self::ESC_END],
+                Self::ESC => [Self::ESC, Self::ESC_ESC],
+                byte => {
+                    buf[pos] = byte;
+                    pos += 1;
+                    continue;
+                }
+            };
+            buf[pos..pos + 2].copy_from_slice(&escaped);
+            pos += 2;
+        }
+
+        // Append the delimiter
+        buf[pos] = Self::END;
+        pos + 1
+    }
+    fn decode_with<R, W>(len: usize, read: R, mut write: W) -> Result<usize, Error>
+    where
+        R: Fn(usize) -> u8,
+        W: FnMut(usize, u8) -> Result<(), Error>,
+    {
+        // Strip the delimiters
+        let start = match len {
+            len if len > 0 && read(0) == Self::END => 1,
+            _ => 0,
+        };
+        let len = match len {
+            len if len > start && read(len - 1) == Self::END => len - 1,
+            len => len,
+        };
+
+        // Unescape the data
+        let (mut index, mut pos) = (start, 0);
+        while index < len {
+            let (byte, step) = match read(index) {
+                Self::END => return Err(e!(InvalidStuffing, "Unexpected delimiter")),
+                Self::ESC if index + 1 < len => match read(index + 1) {
+                    Self::ESC_END => (Self::END, 2),
+                    Self::ESC_ESC => (Self::ESC, 2),
+                    _ => return Err(e!(InvalidStuffing, "Invalid escape sequence")),
+                },
+                Self::ESC => return Err(e!(InvalidStuffing, "Truncated escape sequence")),
+                byte => (byte, 1),
+            };
+
+            // Write the byte
+            write(pos, byte)?;
+            index += step;
+            pos += 1;
+        }
+        Ok(pos)
+    }
+}
@@ -0,0 +1,111 @@
+//! Implements the common interface of byte-stuffing codecs
+
+use crate::{
+    coding::{RawcodeDecode, RawcodeEncode},
+    error::Error,
+};
+use core::cell::Cell;
+
+/// The position of `len` bytes of data at the end of a `buf_len`-sized buffer so that they can be encoded in place
+fn data_start(max_len: usize, len: usize, buf_len: usize) -> Result<usize, Error> {
+    match max_len {
+        expected if buf_len < expected => Err(e!(BufferTooSmall { expected, actual: buf_len })),
+        _ => Ok(buf_len - len),
+    }
+}
+
+/// The in-place coding primitives of byte-stuffing codecs which are not part of the public interface
+pub(crate) mod sealed {
+    use crate::error::Error;
+
+    /// The in-place coding primitives of a byte-stuffing codec
+    pub trait Codec {
+        /// Encodes the data at `buf[start..]` in place and returns the frame length
+        ///
+        /// # Note
+        /// `start` must be at least the encoding overhead (i.e. `Self::max_encoded_len(len) - len`) so that the frame
+        /// never overtakes the data that has not been read yet.
+        fn encode_in_place(buf: &mut [u8], start: usize) -> usize;
+        /// Decodes a frame of `len` bytes that is read via `read` and written via `write` and returns the data length
+        ///
+        /// # Note
+        /// The implementation must never write a byte before the byte at the same position has been read.
+        fn decode_with<R, W>(len: usize, read: R, write: W) -> Result<usize, Error>
+        where
+            R: Fn(usize) -> u8,
+            W: FnMut(usize, u8) -> Result<(), Error>;
+    }
+}
+
+/// A byte-stuffing codec that encodes data into delimited frames
+///
+/// # Note
+/// This trait is sealed and implemented by [`Cobs`](crate::framing::Cobs) and [`Slip`](crate::framing::Slip) only.
+pub trait ByteStuffing: sealed::Codec {
+    /// The worst-case frame length (including the delimiters) for `len` bytes of data (e.g. `T::SIZE`)
+    fn max_encoded_len(len: usize) -> usize;
+
+    /// Encodes `data` into a frame in `buf` and returns the frame length
+    ///
+    /// # Note
+    /// `buf` must be at least `Self::max_encoded_len(data.len())` bytes long.
+    fn encode(data: &[u8], buf: &mut [u8]) -> Result<usize, Error> {
+        let start = data_start(Self::max_encoded_len(data.len()), data.len(), buf.len())?;
+        buf[start..].copy_from_slice(data);
+        Ok(Self::encode_in_place(buf, start))
+    }
+    /// Encodes `value` into a frame in `buf` and returns the frame length
+    ///
+    /// # Note
+    /// `buf` must be at least `Self::max_encoded_len(T::SIZE)` bytes long.
+    fn to_frame<T>(value: &T, buf: &mut [u8]) -> Result<usize, Error>
+    where
+        T: RawcodeEncode,
+    {
+        let start = data_start(Self::max_encoded_len(T::SIZE), T::SIZE, buf.len())?;
+        value.encode(&mut buf[start..])?;
+        Ok(Self::encode_in_place(buf, start))
+    }
+
+    /// Decodes `frame` (with or without delimiters) into `buf` and returns the data length
+    ///
+    /// # Note
+    /// An empty frame (i.e. a frame that consists of delimiters only) decodes to empty data.
+    fn decode(frame: &[u8], buf: &mut [u8]) -> Result<usize, Error> {
+        let actual = buf.len();
+        Self::decode_with(
+            frame.len(),
+            |index| frame[index],
+            |pos, byte| match buf.get_mut(pos) {
+                Some(slot) => {
+                    *slot = byte;
+                    Ok(())
+                }
+                None => {
+                    Err(e!(BufferTooSmall { expected: pos + 1, actual }, "Target buffer is too small for the data"))
+                }
+            },
+        )
+    }
+    /// Decodes `frame` (with or without delimiters) in place and returns the data
+    fn decode_in_place(frame: &mut [u8]) -> Result<&mut [u8], Error> {
+        let cells = Cell::from_mut(&mut *frame).as_slice_of_cells();
+        let len = Self::decode_with(
+            cells.len(),
+            |index| cells[index].get(),
+            |pos, byte| {
+                cells[pos].set(byte);
+                Ok(())
+            },
+        )?;
+        Ok(&mut frame[..len])
+    }
+    /// Decodes a `T` from `frame` (with or without delimiters) in place
+    fn from_frame<T>(frame: &mut [u8]) -> Result<T, Error>
+    where
+        T: RawcodeDecode,
+    {
+        let data = Self::decode_in_place(frame)?;
+        T::decode(data)
+    }
+}
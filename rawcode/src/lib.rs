@@ -1,9 +1,13 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[macro_use]
 pub mod error;
 pub mod coding;
+pub mod framing;
 
 // Re-export coding traits and types
 pub use coding::*;
@@ -0,0 +1,48 @@
+use rawcode::{error::Error, AsciiArray, PrintableAsciiArray, RawcodeConstSize};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [("abcd", b"abcd"), ("\0\n\x7f ", b"\x00\x0a\x7f\x20")];
+
+    for (value, expected) in tests {
+        let mut encoded = [0; AsciiArray::<4>::SIZE];
+        rawcode::to_slice(&AsciiArray::<4>::try_from(value)?, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [(b"abcd", "abcd"), (b"\x00\x0a\x7f\x20", "\0\n\x7f ")];
+    for (encoded, expected) in tests {
+        let decoded: AsciiArray<4> = rawcode::from_slice(encoded)?;
+        assert_eq!(decoded.as_str(), expected);
+    }
+
+    let tests = [(b"ab~ ", "ab~ ")];
+    for (encoded, expected) in tests {
+        let decoded: PrintableAsciiArray<4> = rawcode::from_slice(encoded)?;
+        assert_eq!(decoded.as_str(), expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [b"".as_slice(), b"abc".as_slice(), b"abcde".as_slice(), b"abc\x80".as_slice(), b"\xc3\xa4bc"];
+    for invalid in tests {
+        let result: Result<AsciiArray<4>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+
+    let tests = [b"abc\x00".as_slice(), b"abc\n".as_slice(), b"abc\x7f".as_slice()];
+    for invalid in tests {
+        let result: Result<PrintableAsciiArray<4>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
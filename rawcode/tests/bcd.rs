@@ -0,0 +1,54 @@
+use rawcode::{error::Error, Bcd, RawcodeConstSize};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [(0u32, b"\x00\x00\x00\x00"), (12_345_678, b"\x78\x56\x34\x12"), (99_999_999, b"\x99\x99\x99\x99")];
+    for (value, expected) in tests {
+        let mut encoded = [0; Bcd::<u32, 8>::SIZE];
+        rawcode::to_slice(&Bcd::<u32, 8>::new(value)?, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+
+    let mut encoded = [0; Bcd::<u16, 3>::SIZE];
+    rawcode::to_slice(&Bcd::<u16, 3>::new(987)?, &mut encoded)?;
+    assert_eq!(&encoded, b"\x87\x09");
+    Ok(())
+}
+
+/// Tests construction of invalid values
+#[test]
+fn construct_invalid() {
+    Bcd::<u32, 8>::new(100_000_000).expect_err("Unexpected success");
+    Bcd::<u8, 2>::new(100).expect_err("Unexpected success");
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [(b"\x00\x00\x00\x00", 0u32), (b"\x78\x56\x34\x12", 12_345_678), (b"\x99\x99\x99\x99", 99_999_999)];
+    for (encoded, expected) in tests {
+        let decoded: Bcd<u32, 8> = rawcode::from_slice(encoded)?;
+        assert_eq!(decoded.get(), expected);
+    }
+
+    let decoded: Bcd<u16, 3> = rawcode::from_slice(b"\x87\x09")?;
+    assert_eq!(decoded.get(), 987);
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests =
+        [b"".as_slice(), b"\x00".as_slice(), b"\x0a\x00".as_slice(), b"\xa0\x00".as_slice(), b"\x00\x10".as_slice()];
+    for invalid in tests {
+        let result: Result<Bcd<u16, 3>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+
+    // The value does not fit into the target type
+    let result: Result<Bcd<u8, 3>, Error> = rawcode::from_slice(b"\x56\x02");
+    result.expect_err("Unexpected success");
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use rawcode::{error::Error, BitArray, RawcodeConstSize};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [(&[][..], b"\x00\x00"), (&[0, 9][..], b"\x01\x02"), (&[1, 3, 7, 8, 9][..], b"\x8a\x03")];
+
+    for (ones, expected) in tests {
+        let mut value = BitArray::<10>::new();
+        ones.iter().for_each(|index| value.set(*index, true));
+
+        let mut encoded = [0; BitArray::<10>::SIZE];
+        rawcode::to_slice(&value, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [(b"\x00\x00", &[][..]), (b"\x01\x02", &[0, 9][..]), (b"\x8a\x03", &[1, 3, 7, 8, 9][..])];
+
+    for (encoded, expected) in tests {
+        let decoded: BitArray<10> = rawcode::from_slice(encoded)?;
+        assert!(decoded.ones().eq(expected.iter().copied()));
+        assert_eq!(decoded.count_ones(), expected.len());
+    }
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [b"".as_slice(), b"\x00".as_slice(), b"\x00\x00\x00".as_slice(), b"\x00\x04".as_slice()];
+
+    for invalid in tests {
+        let result: Result<BitArray<10>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use rawcode::{
+    error::{Error, ErrorKind},
+    BoundedVec, RawcodeConstSize,
+};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [
+        (&[][..], b"\x00\x00\x00\x00\x00\x00\x00"),
+        (&[0x0201][..], b"\x01\x01\x02\x00\x00\x00\x00"),
+        (&[1, 2, 0xffff][..], b"\x03\x01\x00\x02\x00\xff\xff"),
+    ];
+
+    for (value, expected) in tests {
+        let mut encoded = [0xff; BoundedVec::<u16, 3, u8>::SIZE];
+        rawcode::to_slice(&BoundedVec::<u16, 3, u8>::try_from(value)?, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests vector operations
+#[test]
+fn operations() -> Result<(), Error> {
+    let mut vec: BoundedVec<u16, 2> = BoundedVec::new();
+    vec.push(7)?;
+    vec.push(4)?;
+    vec.push(9).expect_err("Unexpected success");
+    assert_eq!(vec.len(), 2);
+    assert!(vec.iter().eq([7, 4].iter()));
+
+    assert_eq!(vec.pop(), Some(4));
+    assert_eq!(vec.get(0), Some(&7));
+    assert_eq!(vec.get(1), None);
+    vec.clear();
+    assert!(vec.is_empty());
+    Ok(())
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [
+        (b"\x00\x00\x00\x00\x00\x00\x00", &[][..]),
+        (b"\x01\x01\x02\x00\x00\x00\x00", &[0x0201][..]),
+        (b"\x03\x01\x00\x02\x00\xff\xff", &[1, 2, 0xffff][..]),
+    ];
+
+    for (encoded, expected) in tests {
+        let decoded: BoundedVec<u16, 3, u8> = rawcode::from_slice(encoded)?;
+        assert!(decoded.iter().eq(expected.iter()));
+    }
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [
+        b"".as_slice(),
+        b"\x00\x00\x00\x00\x00\x00".as_slice(),
+        b"\x00\x00\x00\x00\x00\x00\x00\x00".as_slice(),
+        b"\x04\x00\x00\x00\x00\x00\x00".as_slice(),
+        b"\x01\x00\x00\x00\x00\x00\x01".as_slice(),
+    ];
+
+    for invalid in tests {
+        let result: Result<BoundedVec<u16, 3, u8>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
+
+/// Tests the error context of invalid elements and slots
+#[test]
+fn error_context() {
+    let error = rawcode::from_slice::<BoundedVec<bool, 3, u8>>(b"\x02\x00\x01\x00").expect_err("Unexpected success");
+    assert_eq!((error.kind(), error.path(), error.offset()), (ErrorKind::InvalidBool, "[1]", 2));
+
+    let error = rawcode::from_slice::<BoundedVec<bool, 3, u8>>(b"\x01\x00\x00\xff").expect_err("Unexpected success");
+    assert_eq!((error.kind(), error.path(), error.offset()), (ErrorKind::InvalidPadding, "[2]", 3));
+}
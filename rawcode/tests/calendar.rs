@@ -0,0 +1,85 @@
+use rawcode::{error::Error, Date, DateTime, DosDateTime, RawcodeConstSize, TimeOfDay};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [
+        (DateTime::new(Date::new(2024, 2, 29)?, TimeOfDay::new(23, 59, 58)?), b"\xe8\x07\x02\x1d\x17\x3b\x3a"),
+        (DateTime::new(Date::new(1, 1, 1)?, TimeOfDay::new(0, 0, 0)?), b"\x01\x00\x01\x01\x00\x00\x00"),
+    ];
+
+    for (value, expected) in tests {
+        let mut encoded = [0; DateTime::SIZE];
+        rawcode::to_slice(&value, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [
+        (b"\xe8\x07\x02\x1d\x17\x3b\x3a", "2024-02-29T23:59:58"),
+        (b"\xd0\x07\x02\x1d\x00\x00\x00", "2000-02-29T00:00:00"),
+    ];
+
+    for (encoded, expected) in tests {
+        let decoded: DateTime = rawcode::from_slice(encoded)?;
+        assert_eq!(decoded.to_string(), expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [
+        b"".as_slice(),
+        b"\xe8\x07\x02\x1d\x17\x3b".as_slice(),
+        b"\xe8\x07\x02\x1d\x17\x3b\x3a\x00".as_slice(),
+        b"\xe7\x07\x02\x1d\x00\x00\x00".as_slice(),
+        b"\x6c\x07\x02\x1d\x00\x00\x00".as_slice(),
+        b"\xe8\x07\x00\x01\x00\x00\x00".as_slice(),
+        b"\xe8\x07\x0d\x01\x00\x00\x00".as_slice(),
+        b"\xe8\x07\x04\x1f\x00\x00\x00".as_slice(),
+        b"\xe8\x07\x01\x00\x00\x00\x00".as_slice(),
+        b"\xe8\x07\x01\x01\x18\x00\x00".as_slice(),
+        b"\xe8\x07\x01\x01\x00\x3c\x00".as_slice(),
+        b"\xe8\x07\x01\x01\x00\x00\x3c".as_slice(),
+    ];
+
+    for invalid in tests {
+        let result: Result<DateTime, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
+
+/// Tests the MS-DOS date time
+#[test]
+fn dos_date_time() -> Result<(), Error> {
+    // 2024-02-29T23:59:58 => time 0b10111_111011_11101, date 0b0101100_0010_11101
+    let date_time = DateTime::new(Date::new(2024, 2, 29)?, TimeOfDay::new(23, 59, 58)?);
+    let mut encoded = [0; DosDateTime::SIZE];
+    rawcode::to_slice(&DosDateTime::try_from(date_time)?, &mut encoded)?;
+    assert_eq!(&encoded, b"\x7d\xbf\x5d\x58");
+
+    let decoded: DosDateTime = rawcode::from_slice(&encoded)?;
+    assert_eq!(DateTime::from(decoded), date_time);
+
+    // Unrepresentable date times
+    let tests = [(1979, 0), (2108, 0), (2024, 1)];
+    for (year, second) in tests {
+        let date_time = DateTime::new(Date::new(year, 1, 1)?, TimeOfDay::new(0, 0, second)?);
+        DosDateTime::try_from(date_time).expect_err("Unexpected success");
+    }
+
+    // Invalid packed values (day 0, month 13, February 30th, 60 seconds)
+    let tests = [b"\x00\x00\x20\x00", b"\x00\x00\xa1\x01", b"\x00\x00\x5e\x00", b"\x1e\x00\x21\x00"];
+    for invalid in tests {
+        let result: Result<DosDateTime, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
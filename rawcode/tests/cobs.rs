@@ -0,0 +1,89 @@
+use rawcode::{
+    error::{Error, ErrorKind},
+    framing::{ByteStuffing, Cobs},
+    RawcodeConstSize,
+};
+
+/// Encodes `data` into a COBS frame
+fn encode(data: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut buf = vec![0; Cobs::max_encoded_len(data.len())];
+    let len = Cobs::encode(data, &mut buf)?;
+    buf.truncate(len);
+    Ok(buf)
+}
+
+/// Tests encoding of known vectors
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    assert_eq!(encode(b"")?, b"\x01\x00");
+    assert_eq!(encode(b"\x00")?, b"\x01\x01\x00");
+    assert_eq!(encode(b"\x00\x00")?, b"\x01\x01\x01\x00");
+    assert_eq!(encode(b"\x11\x22\x00\x33")?, b"\x03\x11\x22\x02\x33\x00");
+    assert_eq!(encode(b"\x11\x22\x33\x44")?, b"\x05\x11\x22\x33\x44\x00");
+
+    // Full blocks
+    let data: Vec<u8> = (0x01..=0xFE).collect();
+    assert_eq!(encode(&data)?, [&[0xFF], data.as_slice(), &[0x00]].concat());
+    let data: Vec<u8> = (0x01..=0xFF).collect();
+    assert_eq!(encode(&data)?, [&[0xFF], &data[..254], &[0x02, 0xFF, 0x00]].concat());
+
+    // Buffers smaller than the worst case are rejected
+    let error = Cobs::encode(b"\x11", &mut [0; 2]).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::BufferTooSmall { expected: 3, actual: 2 });
+    Ok(())
+}
+
+/// Tests encoding of rawcode values directly into frames
+#[test]
+fn frame_valid() -> Result<(), Error> {
+    // A value without zero bytes is the worst case
+    let value: [u8; 600] = core::array::from_fn(|index| (index % 255) as u8 + 1);
+    let mut buf = [0; Cobs::max_encoded_len(<[u8; 600]>::SIZE)];
+    let len = Cobs::to_frame(&value, &mut buf)?;
+    assert_eq!(len, buf.len());
+    assert_eq!(buf[..len], encode(&value)?);
+    assert_eq!(Cobs::from_frame::<[u8; 600]>(&mut buf[..len])?, value);
+
+    // A value with zero bytes
+    let value = [0x0102_0000u32, 0, 7];
+    let mut buf = [0xAA; Cobs::max_encoded_len(<[u32; 3]>::SIZE) + 4];
+    let len = Cobs::to_frame(&value, &mut buf)?;
+    assert_eq!(Cobs::from_frame::<[u32; 3]>(&mut buf[..len])?, value);
+    Ok(())
+}
+
+/// Tests decoding of known vectors
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let mut buf = [0; 4];
+    let len = Cobs::decode(b"\x03\x11\x22\x02\x33\x00", &mut buf)?;
+    assert_eq!(buf[..len], *b"\x11\x22\x00\x33");
+
+    // The delimiter is optional
+    let len = Cobs::decode(b"\x01\x01\x01", &mut buf)?;
+    assert_eq!(buf[..len], *b"\x00\x00");
+
+    // Empty frames decode to empty data
+    assert_eq!(Cobs::decode(b"", &mut buf)?, 0);
+    assert_eq!(Cobs::decode(b"\x00", &mut buf)?, 0);
+
+    let mut frame = *b"\x03\x11\x22\x02\x33\x00";
+    assert_eq!(Cobs::decode_in_place(&mut frame)?, b"\x11\x22\x00\x33");
+    Ok(())
+}
+
+/// Tests decoding of invalid frames
+#[test]
+fn decode_invalid() {
+    let invalid: [&[u8]; 3] = [b"\x00\x00", b"\x03\x11\x00\x22\x00", b"\x05\x11\x22\x00"];
+    for frame in invalid {
+        let error = Cobs::decode(frame, &mut [0; 8]).expect_err("Unexpected success");
+        assert_eq!(error.kind(), ErrorKind::InvalidStuffing);
+    }
+
+    let error = Cobs::decode(b"\x05\x11\x22\x33\x44\x00", &mut [0; 3]).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::BufferTooSmall { expected: 4, actual: 3 });
+
+    let error = Cobs::from_frame::<u32>(&mut b"\x04\x11\x22\x33\x00".to_owned()).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::TooShort { expected: 4, actual: 3 });
+}
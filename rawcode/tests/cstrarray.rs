@@ -0,0 +1,69 @@
+use rawcode::{error::Error, CStrArray, RawcodeConstSize};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [("", b"\x00\x00\x00\x00"), ("ab", b"ab\x00\x00"), ("abc", b"abc\x00")];
+
+    for (value, expected) in tests {
+        let mut encoded = [0; CStrArray::<4>::SIZE];
+        rawcode::to_slice(&CStrArray::<4>::try_from(value)?, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests construction of invalid values
+#[test]
+fn construct_invalid() {
+    let tests = ["abcd", "a\0"];
+    for invalid in tests {
+        CStrArray::<4>::try_from(invalid).expect_err("Unexpected success");
+    }
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [
+        (b"\x00\x00\x00\x00", c""),
+        (b"ab\x00\x00", c"ab"),
+        (b"a\x00\xff\xff", c"a"),
+        (b"\xff\xfe\x00\x00", c"\xff\xfe"),
+    ];
+
+    for (encoded, expected) in tests {
+        let decoded: CStrArray<4> = rawcode::from_slice(encoded)?;
+        assert_eq!(decoded.as_c_str(), expected);
+
+        // Validate that the trailing garbage is zeroed
+        let mut reencoded = [0xff; CStrArray::<4>::SIZE];
+        rawcode::to_slice(&decoded, &mut reencoded)?;
+        assert_eq!(&reencoded[..expected.count_bytes()], expected.to_bytes());
+        assert!(reencoded[expected.count_bytes()..].iter().all(|byte| *byte == 0));
+    }
+    Ok(())
+}
+
+/// Tests UTF-8 validation
+#[test]
+fn to_str() -> Result<(), Error> {
+    let valid: CStrArray<4> = rawcode::from_slice(b"\xc3\xa4\x00\x00")?;
+    assert_eq!(valid.to_str()?, "ä");
+
+    let invalid: CStrArray<4> = rawcode::from_slice(b"\xc3\x00\x00\x00")?;
+    invalid.to_str().expect_err("Unexpected success");
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [b"".as_slice(), b"\x00\x00\x00".as_slice(), b"\x00\x00\x00\x00\x00".as_slice(), b"abcd".as_slice()];
+
+    for invalid in tests {
+        let result: Result<CStrArray<4>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use rawcode::{
+    error::{Error, ErrorKind},
+    Decoder, Encoder,
+};
+
+/// Tests encoding of consecutive values
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let mut buf = [0xAA; 8];
+    let mut encoder = Encoder::new(&mut buf);
+    encoder.put(&0x0102u16)?;
+    assert_eq!((encoder.position(), encoder.remaining()), (2, 6));
+
+    encoder.skip(2)?;
+    encoder.put(&true)?;
+    encoder.put(&[1u8, 2, 3])?;
+    assert_eq!(encoder.remaining(), 0);
+    encoder.finish()?;
+
+    assert_eq!(buf, *b"\x02\x01\x00\x00\xff\x01\x02\x03");
+    Ok(())
+}
+
+/// Tests encoding into invalid buffers
+#[test]
+fn encode_invalid() -> Result<(), Error> {
+    // Put beyond the end of the buffer
+    let mut buf = [0; 3];
+    let mut encoder = Encoder::new(&mut buf);
+    encoder.put(&0u16)?;
+    let error = encoder.put(&0u16).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::BufferTooSmall { expected: 4, actual: 3 });
+    let error = encoder.skip(2).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::BufferTooSmall { expected: 4, actual: 3 });
+
+    // Finish with remaining bytes
+    let error = encoder.finish().expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::BufferTooLarge { expected: 2, actual: 3 });
+    Ok(())
+}
+
+/// Tests decoding of consecutive values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let mut decoder = Decoder::new(b"\x02\x01\x00\x00\xff\x01\x02\x03");
+    assert_eq!(decoder.peek::<u16>()?, 0x0102);
+    assert_eq!(decoder.get::<u16>()?, 0x0102);
+    assert_eq!((decoder.position(), decoder.remaining()), (2, 6));
+
+    decoder.skip(2)?;
+    assert!(decoder.get::<bool>()?);
+    assert_eq!(decoder.get::<[u8; 3]>()?, [1, 2, 3]);
+    decoder.finish()
+}
+
+/// Tests decoding of invalid data
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    // Invalid values carry their offset and do not advance the position
+    let mut decoder = Decoder::new(b"\x00\x01\x02");
+    decoder.skip(1)?;
+    let error = decoder.get::<bool>().expect_err("Unexpected success");
+    assert_eq!((error.kind(), error.offset()), (ErrorKind::InvalidBool, 1));
+    assert_eq!(decoder.position(), 1);
+
+    // Get beyond the end of the data
+    let error = decoder.get::<u32>().expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::TooShort { expected: 5, actual: 3 });
+    let error = decoder.skip(3).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::TooShort { expected: 4, actual: 3 });
+
+    // Finish with remaining bytes
+    let error = decoder.finish().expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::TooLong { expected: 1, actual: 3 });
+    Ok(())
+}
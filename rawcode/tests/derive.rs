@@ -1,6 +1,9 @@
 #![cfg(feature = "rawcode_derive")]
 
-use rawcode::{error::Error, RawcodeConstSize, RawcodeDecode, RawcodeEncode, StrArray};
+use rawcode::{
+    error::{Error, ErrorKind},
+    RawcodeConstSize, RawcodeDecode, RawcodeEncode, StrArray,
+};
 use rawcode_derive::Rawcode;
 
 /// A named test struct
@@ -74,3 +77,90 @@ fn generic() -> Result<(), Error> {
     assert_eq!(raw, decoded);
     Ok(())
 }
+
+/// An inner test struct
+#[derive(Debug, PartialEq, Eq, Rawcode)]
+struct Inner {
+    id: u16,
+    flag: bool,
+}
+
+/// An outer test struct
+#[derive(Debug, PartialEq, Eq, Rawcode)]
+struct Outer {
+    header: u32,
+    list: [Inner; 4],
+}
+
+/// Tests the error context of a nested struct
+#[test]
+fn error_context() {
+    // Corrupt the flag of the fourth inner struct
+    let mut buf = [0; Outer::SIZE];
+    buf[4 + 3 * Inner::SIZE + 2] = 0x01;
+
+    let error = Outer::decode(&buf).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::InvalidBool);
+    assert_eq!(error.root(), Some("Outer"));
+    assert_eq!(error.path(), ".list[3].flag");
+    assert_eq!(error.offset(), 4 + 3 * Inner::SIZE + 2);
+    assert!(error.to_string().ends_with("(in `Outer.list[3].flag` at byte offset 15)"));
+
+    // Tuple struct fields are referenced by their index
+    let error = Unnamed::decode(&[0x01; Unnamed::SIZE]).expect_err("Unexpected success");
+    assert_eq!((error.root(), error.path(), error.offset()), (Some("Unnamed"), ".0", 0));
+}
+
+/// Tests that derived structs reject data and buffers with an invalid length
+#[test]
+fn length() {
+    // Oversized and undersized data
+    let error = Outer::decode(&[0; Outer::SIZE + 1]).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::TooLong { expected: Outer::SIZE, actual: Outer::SIZE + 1 });
+    assert_eq!((error.root(), error.path()), (Some("Outer"), ""));
+    let error = Outer::decode(&[0; Outer::SIZE - 1]).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::TooShort { expected: Outer::SIZE, actual: Outer::SIZE - 1 });
+
+    // Oversized and undersized buffers
+    let outer = Outer::decode(&[0; Outer::SIZE]).expect("Failed to decode struct");
+    let error = outer.encode(&mut [0; Outer::SIZE + 1]).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::BufferTooLarge { expected: Outer::SIZE, actual: Outer::SIZE + 1 });
+    let error = outer.encode(&mut [0; Outer::SIZE - 1]).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::BufferTooSmall { expected: Outer::SIZE, actual: Outer::SIZE - 1 });
+
+    // Trailing data is also reported during validation
+    #[cfg(feature = "alloc")]
+    {
+        let errors = rawcode::validate::<Outer>(&[0; Outer::SIZE + 1]);
+        let kinds: Vec<_> = errors.iter().map(|error| error.kind()).collect();
+        assert_eq!(kinds, [ErrorKind::TooLong { expected: Outer::SIZE, actual: Outer::SIZE + 1 }]);
+    }
+}
+
+/// Tests validation of a nested struct
+#[test]
+#[cfg(feature = "alloc")]
+fn validate() {
+    // Corrupt the flags of the first and third inner struct and truncate the buffer
+    let mut buf = [0; Outer::SIZE];
+    buf[4 + 2] = 0x01;
+    buf[4 + 2 * Inner::SIZE + 2] = 0x02;
+
+    let errors = rawcode::validate::<Outer>(&buf);
+    let errors: Vec<_> = errors.iter().map(|error| (error.kind(), error.path().to_string(), error.offset())).collect();
+    assert_eq!(
+        errors,
+        [
+            (ErrorKind::InvalidBool, ".list[0].flag".to_string(), 6),
+            (ErrorKind::InvalidBool, ".list[2].flag".to_string(), 12)
+        ]
+    );
+
+    // Truncated fields are reported individually
+    let errors = rawcode::validate::<Outer>(&buf[..2]);
+    let paths: Vec<_> = errors.iter().map(|error| error.path()).collect();
+    assert_eq!(paths, [".header", ".list"]);
+
+    // Valid data yields no errors
+    assert!(rawcode::validate::<Outer>(&[0; Outer::SIZE]).is_empty());
+}
@@ -0,0 +1,117 @@
+use rawcode::{
+    error::{Error, ErrorKind},
+    RawcodeConstSize, RawcodeDecode, StrArray,
+};
+
+/// Tests the error kinds of length mismatches
+#[test]
+fn length_kinds() {
+    let error = rawcode::from_slice::<u32>(b"\x01\x02").expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::TooShort { expected: 4, actual: 2 });
+    assert_eq!(error.message(), "Encoded data is too short");
+
+    let error = rawcode::from_slice::<u16>(b"\x01\x02\x03").expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::TooLong { expected: 2, actual: 3 });
+
+    let error = rawcode::to_slice(&7u32, &mut [0; 3]).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::BufferTooSmall { expected: 4, actual: 3 });
+
+    let error = rawcode::to_slice(&7u32, &mut [0; 5]).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::BufferTooLarge { expected: 4, actual: 5 });
+
+    let mut pos = 3;
+    let error = rawcode::from_slice_at::<u16>(b"\x01\x02\x03\x04", &mut pos).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::TooShort { expected: 5, actual: 4 });
+}
+
+/// Tests the error kinds of invalid content
+#[test]
+fn content_kinds() {
+    let error = rawcode::from_slice::<bool>(b"\x01").expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::InvalidBool);
+
+    let error = rawcode::from_slice::<StrArray<2>>(b"\xff\xfe").expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::InvalidUtf8);
+
+    let error = rawcode::from_slice::<core::net::IpAddr>(&[5; 17]).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::InvalidTag);
+
+    let error: Error = StrArray::<2>::try_from("abc").expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::InvalidLength);
+}
+
+/// Tests the field path and offset context
+#[test]
+fn context() {
+    // Arrays attach the element index and offset
+    let error = rawcode::from_slice::<[bool; 4]>(b"\x00\xff\x02\x00").expect_err("Unexpected success");
+    assert_eq!((error.root(), error.path(), error.offset()), (None, "[2]", 2));
+
+    // Outer segments are dropped if the path becomes too long
+    let error = rawcode::from_slice::<bool>(b"\x01").expect_err("Unexpected success");
+    let error = error.with_field("a_rather_long_field_name").with_field("another_long_field_name").with_root("Root");
+    assert_eq!(error.path(), ".a_rather_long_field_name");
+    assert!(error.is_path_truncated());
+
+    // Shorter outer segments are dropped too once the path has been truncated
+    let error = rawcode::from_slice::<bool>(b"\x01").expect_err("Unexpected success");
+    let error = error.with_field("abcdefghijklmnopqrstuvwxy").with_index(12345).with_field("a");
+    assert_eq!(error.path(), ".abcdefghijklmnopqrstuvwxy");
+    assert!(error.is_path_truncated());
+}
+
+/// A type with a hand-written decode implementation
+#[derive(Debug)]
+struct Even(u8);
+impl RawcodeConstSize for Even {
+    const SIZE: usize = u8::SIZE;
+}
+impl RawcodeDecode for Even {
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        match u8::decode(buf)? {
+            value if value % 2 != 0 => Err(Error::custom_with_detail("Value is not even", value.into())),
+            value => Ok(Self(value)),
+        }
+    }
+}
+
+/// Tests user-defined errors
+#[test]
+fn custom() {
+    let error = rawcode::from_slice::<Even>(b"\x07").expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::Custom { detail: Some(7) });
+    assert_eq!(error.message(), "Value is not even");
+    assert_eq!(error.location().0, file!());
+    assert_eq!(rawcode::from_slice::<Even>(b"\x08").map(|even| even.0), Ok(8));
+
+    // Built-in errors report the location within the crate
+    let error = rawcode::from_slice::<bool>(b"\x01").expect_err("Unexpected success");
+    assert!(error.location().0.ends_with("boolean.rs"));
+}
+
+/// Tests wrapping of underlying errors
+#[test]
+#[cfg(feature = "std")]
+fn source() {
+    use std::error::Error as _;
+
+    let underlying = "x".parse::<u8>().expect_err("Unexpected success");
+    let error = Error::custom("Failed to parse value").with_source(underlying.clone());
+    assert_eq!(error.kind(), ErrorKind::Custom { detail: None });
+
+    let source = error.source().expect("Missing source");
+    assert_eq!(source.to_string(), underlying.to_string());
+}
+
+/// Tests the formatting of error kinds
+#[test]
+#[cfg(feature = "alloc")]
+fn kind_display() {
+    extern crate alloc;
+    use alloc::string::ToString;
+
+    let kind = ErrorKind::TooShort { expected: 4, actual: 2 };
+    assert_eq!(kind.to_string(), "Encoded data is too short (expected 4 bytes, got 2)");
+    assert_eq!(ErrorKind::Custom { detail: Some(7) }.to_string(), "Custom error (detail 7)");
+    assert_eq!(ErrorKind::InvalidBool.to_string(), "Invalid boolean");
+}
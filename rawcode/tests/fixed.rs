@@ -0,0 +1,80 @@
+use rawcode::{error::Error, Fixed, RawcodeConstSize, I16F16, I8F8, U8F8};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [(0.0, b"\x00\x00\x00\x00"), (1.5, b"\x00\x80\x01\x00"), (-1.0, b"\x00\x00\xff\xff")];
+
+    for (value, expected) in tests {
+        let mut encoded = [0; I16F16::SIZE];
+        rawcode::to_slice(&I16F16::from_f64(value)?, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests float conversions
+#[test]
+fn convert() -> Result<(), Error> {
+    assert_eq!(I8F8::from_f64(1.0 / 512.0)?.to_bits(), 1);
+    assert_eq!(I8F8::from_f64(-1.0 / 512.0)?.to_bits(), -1);
+    assert_eq!(I8F8::from_f32(128.0 - 1.0 / 256.0)?, I8F8::MAX);
+    assert_eq!(I8F8::from_f64(-128.0)?, I8F8::MIN);
+    assert_eq!(U8F8::from_bits(0x0180).to_f32(), 1.5);
+
+    let tests = [128.0, -128.01, f64::NAN, f64::INFINITY];
+    for invalid in tests {
+        I8F8::from_f64(invalid).expect_err("Unexpected success");
+    }
+    U8F8::from_f64(-1.0).expect_err("Unexpected success");
+    Ok(())
+}
+
+/// Tests rounding at the half-way boundaries
+#[test]
+fn rounding() -> Result<(), Error> {
+    let tests = [(0.49999999999999994, 0), (0.5, 1), (-0.5, -1), (2.5, 3), (-2.5, -3), (-0.49999999999999994, 0)];
+    for (value, expected) in tests {
+        assert_eq!(Fixed::<i32, 0>::from_f64(value)?.to_bits(), expected);
+    }
+    Ok(())
+}
+
+/// Tests checked arithmetic
+#[test]
+fn arithmetic() -> Result<(), Error> {
+    let (a, b) = (I16F16::from_f64(2.5)?, I16F16::from_f64(-0.5)?);
+    assert_eq!(a.checked_add(b), Some(I16F16::from_f64(2.0)?));
+    assert_eq!(a.checked_sub(b), Some(I16F16::from_f64(3.0)?));
+    assert_eq!(a.checked_mul(b), Some(I16F16::from_f64(-1.25)?));
+    assert_eq!(a.checked_div(b), Some(I16F16::from_f64(-5.0)?));
+
+    assert_eq!(I16F16::MAX.checked_add(a), None);
+    assert_eq!(I16F16::MAX.checked_mul(a), None);
+    assert_eq!(a.checked_div(Fixed::from_bits(0)), None);
+    Ok(())
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [(b"\x00\x00\x00\x00", 0.0), (b"\x00\x80\x01\x00", 1.5), (b"\x00\x00\xff\xff", -1.0)];
+
+    for (encoded, expected) in tests {
+        let decoded: I16F16 = rawcode::from_slice(encoded)?;
+        assert_eq!(decoded.to_f64(), expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [b"".as_slice(), b"\x01\x02\x03".as_slice(), b"\xFF\xFF\xFF\xFF\xFF".as_slice()];
+
+    for invalid in tests {
+        let result: Result<I16F16, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use rawcode::{
+    error::{Error, ErrorKind},
+    Flag, FlagSet, RawcodeConstSize,
+};
+
+/// A test flag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Permission {
+    Read,
+    Write,
+    Execute,
+}
+impl Flag for Permission {
+    type Bits = u16;
+    const ALL: &'static [Self] = &[Self::Read, Self::Write, Self::Execute];
+
+    fn bit(self) -> u32 {
+        match self {
+            Self::Read => 0,
+            Self::Write => 1,
+            Self::Execute => 9,
+        }
+    }
+}
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [
+        (&[][..], b"\x00\x00"),
+        (&[Permission::Read][..], b"\x01\x00"),
+        (&[Permission::Write, Permission::Execute][..], b"\x02\x02"),
+    ];
+
+    for (flags, expected) in tests {
+        let mut encoded = [0; FlagSet::<Permission>::SIZE];
+        rawcode::to_slice(&flags.iter().copied().collect::<FlagSet<_>>(), &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests flag set operations
+#[test]
+fn operations() {
+    let mut set = FlagSet::from([Permission::Read, Permission::Execute]);
+    assert!(set.contains(Permission::Read));
+    assert!(!set.contains(Permission::Write));
+
+    set.insert(Permission::Write);
+    set.remove(Permission::Read);
+    assert!(set.iter().eq([Permission::Write, Permission::Execute]));
+    assert!(FlagSet::from([Permission::Read]) < set);
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [
+        (b"\x00\x00", &[][..]),
+        (b"\x01\x00", &[Permission::Read][..]),
+        (b"\x03\x02", &[Permission::Read, Permission::Write, Permission::Execute][..]),
+    ];
+
+    for (encoded, expected) in tests {
+        let decoded: FlagSet<Permission> = rawcode::from_slice(encoded)?;
+        assert!(decoded.iter().eq(expected.iter().copied()));
+    }
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [b"".as_slice(), b"\x00".as_slice(), b"\x04\x00".as_slice(), b"\x00\x80".as_slice()];
+
+    for invalid in tests {
+        let result: Result<FlagSet<Permission>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
+
+/// A test flag with an invalid bit index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Oversized;
+impl Flag for Oversized {
+    type Bits = u8;
+    const ALL: &'static [Self] = &[Self];
+
+    fn bit(self) -> u32 {
+        128
+    }
+}
+
+/// Tests that invalid bit indices are rejected instead of panicking during decoding
+#[test]
+fn decode_oversized() {
+    let error = rawcode::from_slice::<FlagSet<Oversized>>(b"\x00").expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::OutOfRange);
+}
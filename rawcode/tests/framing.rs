@@ -0,0 +1,109 @@
+use rawcode::{
+    error::{Error, ErrorKind},
+    framing::{Crc16, Crc32, Frame, FrameReceiver},
+    RawcodeConstSize, RawcodeDecode, RawcodeEncode,
+};
+
+/// A test frame
+type TestFrame = Frame<u32, Crc16, 0xBEEF>;
+
+/// Encodes a test frame
+fn frame(payload: u32) -> [u8; TestFrame::SIZE] {
+    let mut buf = [0; TestFrame::SIZE];
+    TestFrame::new(payload).encode(&mut buf).expect("Failed to encode frame");
+    buf
+}
+
+/// Tests the checksums against the standard check values
+#[test]
+fn checksums() {
+    assert_eq!(Crc16::compute(b"123456789"), 0x29B1);
+    assert_eq!(Crc32::compute(b"123456789"), 0xCBF4_3926);
+}
+
+/// Tests encoding of frames
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    assert_eq!(frame(0x0403_0201), *b"\xEF\xBE\x01\x02\x03\x04\xC3\x89");
+
+    let mut buf = [0; Frame::<u8>::SIZE];
+    Frame::<u8>::new(0x31).encode(&mut buf)?;
+    assert_eq!(buf, *b"\xAA\x55\x31\xB7\xEF\xDC\x83");
+    Ok(())
+}
+
+/// Tests decoding of frames
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let frame = TestFrame::decode(&frame(7))?;
+    assert_eq!(*frame.payload(), 7);
+    Ok(())
+}
+
+/// Tests decoding of invalid frames
+#[test]
+fn decode_invalid() {
+    let mut buf = frame(7);
+    buf[0] = 0x00;
+    let error = TestFrame::decode(&buf).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::InvalidSync);
+
+    let mut buf = frame(7);
+    buf[2] ^= 0x01;
+    let error = TestFrame::decode(&buf).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::InvalidChecksum);
+
+    let error = Frame::<bool>::decode(b"\xAA\x55\x01\x1B\xDF\x05\xA5").expect_err("Unexpected success");
+    assert_eq!((error.kind(), error.offset()), (ErrorKind::InvalidBool, 2));
+}
+
+/// Tests receiving frames from a corrupted stream
+#[test]
+fn receiver() -> Result<(), Error> {
+    let mut receiver = FrameReceiver::<u32, { TestFrame::SIZE }, Crc16, 0xBEEF>::new();
+
+    // A frame that is split across chunks
+    let buf = frame(1);
+    let (head, tail) = buf.split_at(3);
+    assert!(receiver.push(head).next().is_none());
+    let payloads: Vec<_> = receiver.push(tail).collect::<Result<_, _>>()?;
+    assert_eq!(payloads, [1]);
+
+    // Garbage, a corrupted frame that contains a sync word and a valid frame
+    let mut corrupted = frame(0xBEEF_0000);
+    corrupted[6] ^= 0xFF;
+    let mut stream = b"\x00\xEF\x13".to_vec();
+    stream.extend_from_slice(&corrupted);
+    stream.extend_from_slice(&frame(2));
+    let payloads: Vec<_> = receiver.push(&stream).collect::<Result<_, _>>()?;
+    assert_eq!(payloads, [2]);
+
+    // Validate the statistics
+    let stats = receiver.stats();
+    assert_eq!((stats.frames(), stats.bad_frames()), (2, 2));
+    assert_eq!(stats.dropped_bytes(), 3 + TestFrame::SIZE);
+    assert_eq!(receiver.pending(), 0);
+    Ok(())
+}
+
+/// Tests that the remaining frames are skipped and counted if the payload iterator is dropped early
+#[test]
+fn receiver_drop() -> Result<(), Error> {
+    let mut receiver = FrameReceiver::<u32, { TestFrame::SIZE }, Crc16, 0xBEEF>::new();
+    let (last, split) = (frame(3), 3);
+    let stream = [&frame(1)[..], &frame(2), &last[..split]].concat();
+
+    // Drop the iterator after the first frame
+    let mut payloads = receiver.push(&stream);
+    assert_eq!(payloads.next().transpose()?, Some(1));
+    drop(payloads);
+
+    let stats = receiver.stats();
+    assert_eq!((stats.frames(), stats.skipped_frames(), stats.dropped_bytes()), (2, 1, 0));
+    assert_eq!(receiver.pending(), split);
+
+    // The receiver is still in sync
+    let payloads: Vec<_> = receiver.push(&last[split..]).collect::<Result<_, _>>()?;
+    assert_eq!(payloads, [3]);
+    Ok(())
+}
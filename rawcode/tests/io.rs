@@ -0,0 +1,71 @@
+#![cfg(feature = "std")]
+
+use rawcode::{
+    error::{Error, ErrorKind},
+    RecordReader,
+};
+use std::io::{self, Cursor, Read};
+
+/// A reader that fails after the underlying data has been consumed
+struct FailingReader(Cursor<Vec<u8>>);
+impl Read for FailingReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.0.read(buf)? {
+            0 => Err(io::Error::other("Test error")),
+            read => Ok(read),
+        }
+    }
+}
+
+/// Tests reading and writing of single values
+#[test]
+fn read_write() -> Result<(), Error> {
+    let mut buf = Vec::new();
+    rawcode::write_to(&0x0102_0304u32, &mut buf)?;
+    rawcode::write_to(&true, &mut buf)?;
+    assert_eq!(buf, b"\x04\x03\x02\x01\xff");
+
+    let mut reader = Cursor::new(buf);
+    assert_eq!(rawcode::read_from::<u32, _>(&mut reader)?, 0x0102_0304);
+    assert!(rawcode::read_from::<bool, _>(&mut reader)?);
+
+    let error = rawcode::read_from::<u16, _>(&mut reader).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::TruncatedRecord { expected: 2, actual: 0 });
+    Ok(())
+}
+
+/// Tests iterating over records
+#[test]
+fn record_reader() -> Result<(), Error> {
+    // A clean EOF ends the iteration
+    let records: Vec<u16> = RecordReader::new(Cursor::new(b"\x01\x00\x02\x00")).collect::<Result<_, _>>()?;
+    assert_eq!(records, [1, 2]);
+
+    // Invalid records do not stop the iteration, a truncated trailing record does
+    let mut reader = RecordReader::<bool, _>::new(Cursor::new(b"\xff\x02\x00"));
+    assert!(reader.next().expect("Missing record")?);
+    let error = reader.next().expect("Missing record").expect_err("Unexpected success");
+    assert_eq!((error.kind(), error.path(), error.offset()), (ErrorKind::InvalidBool, "[1]", 1));
+    assert!(!reader.next().expect("Missing record")?);
+    assert!(reader.next().is_none());
+
+    // Truncated trailing records are reported as such
+    let mut reader = RecordReader::<u32, _>::new(Cursor::new(b"\x01\x00\x00\x00\x02\x00"));
+    assert_eq!(reader.next().expect("Missing record")?, 1);
+    let error = reader.next().expect("Missing record").expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::TruncatedRecord { expected: 4, actual: 2 });
+    assert!(reader.next().is_none());
+    Ok(())
+}
+
+/// Tests I/O error propagation
+#[test]
+fn io_error() {
+    use std::error::Error as _;
+
+    let mut reader = RecordReader::<u16, _>::new(FailingReader(Cursor::new(vec![0x01])));
+    let error = reader.next().expect("Missing record").expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::Io);
+    assert_eq!(error.source().expect("Missing source").to_string(), "Test error");
+    assert!(reader.next().is_none());
+}
@@ -0,0 +1,49 @@
+use rawcode::{error::Error, Latin1Array, RawcodeConstSize};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [("abcd", b"abcd"), ("äöü\u{ff}", b"\xe4\xf6\xfc\xff")];
+
+    for (value, expected) in tests {
+        let mut encoded = [0; Latin1Array::<4>::SIZE];
+        rawcode::to_slice(&Latin1Array::<4>::try_from(value)?, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests construction of invalid values
+#[test]
+fn construct_invalid() {
+    let tests = ["abc", "abcde", "abc€", "äöü"];
+    for invalid in tests {
+        Latin1Array::<4>::try_from(invalid).expect_err("Unexpected success");
+    }
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [(b"abcd", "abcd"), (b"\xe4\xf6\xfc\xff", "äöü\u{ff}")];
+
+    for (encoded, expected) in tests {
+        let decoded: Latin1Array<4> = rawcode::from_slice(encoded)?;
+        assert!(decoded.chars().eq(expected.chars()));
+        #[cfg(feature = "alloc")]
+        assert_eq!(String::from(decoded), expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [b"".as_slice(), b"abc".as_slice(), b"abcde".as_slice()];
+
+    for invalid in tests {
+        let result: Result<Latin1Array<4>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
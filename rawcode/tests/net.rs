@@ -0,0 +1,64 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+use rawcode::{error::Error, Eui64, MacAddr, RawcodeConstSize};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let mut encoded = [0; SocketAddrV4::SIZE];
+    rawcode::to_slice(&SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 1), 0x1f90), &mut encoded)?;
+    assert_eq!(&encoded, b"\xc0\xa8\x00\x01\x1f\x90");
+
+    let mut encoded = [0; SocketAddrV6::SIZE];
+    rawcode::to_slice(&SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 0), &mut encoded)?;
+    assert_eq!(&encoded, b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x01\xbb");
+
+    let tests = [
+        (
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            b"\x04\x0a\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        ),
+        (IpAddr::V6(Ipv6Addr::LOCALHOST), b"\x06\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01"),
+    ];
+    for (value, expected) in tests {
+        let mut encoded = [0xff; IpAddr::SIZE];
+        rawcode::to_slice(&value, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+
+    let mut encoded = [0; MacAddr::SIZE];
+    rawcode::to_slice(&MacAddr::new([0x00, 0x1b, 0x44, 0x11, 0x3a, 0xb7]), &mut encoded)?;
+    assert_eq!(&encoded, b"\x00\x1b\x44\x11\x3a\xb7");
+    Ok(())
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let decoded: SocketAddrV4 = rawcode::from_slice(b"\xc0\xa8\x00\x01\x1f\x90")?;
+    assert_eq!(decoded, SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 1), 8080));
+
+    let decoded: IpAddr = rawcode::from_slice(b"\x04\x0a\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00")?;
+    assert_eq!(decoded, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
+
+    let decoded: Eui64 = rawcode::from_slice(b"\x00\x1b\x44\xff\xfe\x11\x3a\xb7")?;
+    assert_eq!(decoded.to_string(), "00:1b:44:ff:fe:11:3a:b7");
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [
+        b"".as_slice(),
+        b"\x04\x0a\x00\x00\x01".as_slice(),
+        b"\x05\x0a\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00".as_slice(),
+        b"\x04\x0a\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01".as_slice(),
+        b"\x06\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x00".as_slice(),
+    ];
+
+    for invalid in tests {
+        let result: Result<IpAddr, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use rawcode::{error::Error, RawcodeConstSize, I24, I48, U24, U48};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [(0u32, b"\x00\x00\x00"), (255, b"\xff\x00\x00"), (0x00ff_ffff, b"\xff\xff\xff")];
+    for (value, expected) in tests {
+        let mut encoded = [0; U24::SIZE];
+        rawcode::to_slice(&U24::try_from(value)?, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+
+    let tests =
+        [(0i64, b"\x00\x00\x00\x00\x00\x00"), (-1, b"\xff\xff\xff\xff\xff\xff"), (-2, b"\xfe\xff\xff\xff\xff\xff")];
+    for (value, expected) in tests {
+        let mut encoded = [0; I48::SIZE];
+        rawcode::to_slice(&I48::try_from(value)?, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [
+        (b"\x00\x00\x00", 0i32),
+        (b"\xff\xff\x7f", 0x007f_ffff),
+        (b"\x00\x00\x80", -0x0080_0000),
+        (b"\xff\xff\xff", -1),
+    ];
+    for (encoded, expected) in tests {
+        let decoded: I24 = rawcode::from_slice(encoded)?;
+        assert_eq!(decoded.get(), expected);
+    }
+
+    let tests = [(b"\x00\x00\x00\x00\x00\x00", 0u64), (b"\xff\xff\xff\xff\xff\xff", 0x0000_ffff_ffff_ffff)];
+    for (encoded, expected) in tests {
+        let decoded: U48 = rawcode::from_slice(encoded)?;
+        assert_eq!(decoded.get(), expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [b"".as_slice(), b"\x01\x02".as_slice(), b"\xFF\xFF\xFF\xFF".as_slice()];
+
+    for invalid in tests {
+        let result: Result<U24, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
+
+/// Tests construction of out-of-range values
+#[test]
+fn construct_invalid() {
+    assert!(U24::new(0x0100_0000).is_none());
+    assert!(I24::new(0x0080_0000).is_none());
+    assert!(I24::new(-0x0080_0001).is_none());
+    assert!(I48::try_from(i64::MIN).is_err());
+    assert_eq!(I24::new(-0x0080_0000), Some(I24::MIN));
+    assert_eq!(U48::new(0x0000_ffff_ffff_ffff), Some(U48::MAX));
+}
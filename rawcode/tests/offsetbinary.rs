@@ -0,0 +1,38 @@
+use rawcode::{error::Error, OffsetBinary, RawcodeConstSize};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [(i16::MIN, b"\x00\x00"), (-1, b"\xff\x7f"), (0, b"\x00\x80"), (i16::MAX, b"\xff\xff")];
+    for (value, expected) in tests {
+        let mut encoded = [0; OffsetBinary::<i16>::SIZE];
+        rawcode::to_slice(&OffsetBinary::new(value), &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [(b"\x00\x00", i16::MIN), (b"\xff\x7f", -1), (b"\x00\x80", 0), (b"\xff\xff", i16::MAX)];
+    for (encoded, expected) in tests {
+        let decoded: OffsetBinary<i16> = rawcode::from_slice(encoded)?;
+        assert_eq!(decoded.get(), expected);
+    }
+
+    let decoded: OffsetBinary<i8> = rawcode::from_slice(b"\x81")?;
+    assert_eq!(i8::from(decoded), 1);
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [b"".as_slice(), b"\x00".as_slice(), b"\x00\x00\x00".as_slice()];
+    for invalid in tests {
+        let result: Result<OffsetBinary<i16>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use rawcode::{
+    error::{Error, ErrorKind},
+    PaddedStr, RawcodeConstSize, SpacePaddedStr,
+};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [("", b"\x00\x00\x00\x00"), ("ab", b"ab\x00\x00"), ("abcd", b"abcd")];
+    for (value, expected) in tests {
+        let mut encoded = [0; PaddedStr::<4>::SIZE];
+        rawcode::to_slice(&PaddedStr::<4>::try_from(value)?, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+
+    let tests = [("", b"    "), ("a b", b"a b "), ("äb", b"\xc3\xa4b ")];
+    for (value, expected) in tests {
+        let mut encoded = [0; SpacePaddedStr::<4>::SIZE];
+        rawcode::to_slice(&SpacePaddedStr::<4>::try_from(value)?, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests construction of invalid values
+#[test]
+fn construct_invalid() {
+    let tests = ["abcde", "a\0", "\0"];
+    for invalid in tests {
+        PaddedStr::<4>::try_from(invalid).expect_err("Unexpected success");
+    }
+
+    let tests = ["abcde", "ab ", "a\0"];
+    for invalid in tests {
+        SpacePaddedStr::<4>::try_from(invalid).expect_err("Unexpected success");
+    }
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [(b"\x00\x00\x00\x00", ""), (b"ab\x00\x00", "ab"), (b"abcd", "abcd")];
+    for (encoded, expected) in tests {
+        let decoded: PaddedStr<4> = rawcode::from_slice(encoded)?;
+        assert_eq!(decoded.as_str(), expected);
+    }
+
+    let tests = [(b"    ", ""), (b"a b ", "a b"), (b"abcd", "abcd")];
+    for (encoded, expected) in tests {
+        let decoded: SpacePaddedStr<4> = rawcode::from_slice(encoded)?;
+        assert_eq!(decoded.as_str(), expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [b"".as_slice(), b"abc".as_slice(), b"abcde".as_slice(), b"a\x00b\x00", b"\xff\x00\x00\x00"];
+    for invalid in tests {
+        let result: Result<PaddedStr<4>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+
+    let tests = [b"a\x00  ".as_slice(), b"\xc3   ".as_slice()];
+    for invalid in tests {
+        let result: Result<SpacePaddedStr<4>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+
+    // Embedded NUL bytes are invalid text
+    let error = rawcode::from_slice::<SpacePaddedStr<4>>(b"a\x00b ").expect_err("Unexpected success");
+    assert_eq!((error.kind(), error.to_string().contains("NUL byte")), (ErrorKind::InvalidText, true));
+    Ok(())
+}
+
+/// Tests the error kinds of invalid strings
+#[test]
+fn from_str_invalid() {
+    let error = PaddedStr::<4>::try_from("abcde").expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::InvalidLength);
+    let error = PaddedStr::<4>::try_from("a\0b").expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::InvalidText);
+    let error = SpacePaddedStr::<4>::try_from("ab ").expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::InvalidText);
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "alloc")]
+
+extern crate alloc;
+
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+use rawcode::{error::Error, RawcodeConstSize};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let mut encoded = [0; Box::<u16>::SIZE];
+    rawcode::to_slice(&Box::new(0x0102u16), &mut encoded)?;
+    assert_eq!(&encoded, b"\x02\x01");
+
+    let mut encoded = [0; Rc::<[bool; 2]>::SIZE];
+    rawcode::to_slice(&Rc::new([true, false]), &mut encoded)?;
+    assert_eq!(&encoded, b"\xff\x00");
+    Ok(())
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let decoded: Box<u16> = rawcode::from_slice(b"\x02\x01")?;
+    assert_eq!(*decoded, 0x0102);
+
+    let decoded: Arc<[bool; 2]> = rawcode::from_slice(b"\xff\x00")?;
+    assert_eq!(*decoded, [true, false]);
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [b"".as_slice(), b"\x01".as_slice(), b"\x01\x02\x03".as_slice()];
+    for invalid in tests {
+        let result: Result<Box<u16>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+
+    // Validation is forwarded to the pointee
+    let errors = rawcode::validate::<Rc<[bool; 2]>>(b"\x01\x02");
+    assert_eq!(errors.len(), 2);
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use rawcode::{error::Error, Ranged, RawcodeConstSize};
+
+/// A WiFi channel
+type Channel = Ranged<u8, 1, 14>;
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [(1u8, b"\x01"), (14, b"\x0e")];
+
+    for (value, expected) in tests {
+        let mut encoded = [0; Channel::SIZE];
+        rawcode::to_slice(&Channel::new(value)?, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests construction of invalid values
+#[test]
+fn construct_invalid() {
+    Channel::new(0).expect_err("Unexpected success");
+    Channel::new(15).expect_err("Unexpected success");
+    Ranged::<i16, -10, 10>::new(-11).expect_err("Unexpected success");
+    Ranged::<u128, 0, 10>::new(u128::MAX).expect_err("Unexpected success");
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [(b"\x01", 1), (b"\x0e", 14)];
+    for (encoded, expected) in tests {
+        let decoded: Channel = rawcode::from_slice(encoded)?;
+        assert_eq!(decoded.get(), expected);
+    }
+
+    let decoded: Ranged<i16, -10, 10> = rawcode::from_slice(b"\xf6\xff")?;
+    assert_eq!(decoded.get(), -10);
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [b"".as_slice(), b"\x00".as_slice(), b"\x0f".as_slice(), b"\xff".as_slice(), b"\x01\x01".as_slice()];
+
+    for invalid in tests {
+        let result: Result<Channel, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use rawcode::{error::Error, IsizeAs, RawcodeConstSize, UsizeAs};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [(0usize, b"\x00\x00\x00\x00"), (255, b"\xff\x00\x00\x00"), (0xffff_ffff, b"\xff\xff\xff\xff")];
+    for (value, expected) in tests {
+        let mut encoded = [0; UsizeAs::<u32>::SIZE];
+        rawcode::to_slice(&UsizeAs::<u32>::new(value), &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+
+    let tests = [(0isize, b"\x00\x00"), (-1, b"\xff\xff"), (-32_768, b"\x00\x80")];
+    for (value, expected) in tests {
+        let mut encoded = [0; IsizeAs::<i16>::SIZE];
+        rawcode::to_slice(&IsizeAs::<i16>::new(value), &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests encoding of values that do not fit into the wire type
+#[test]
+fn encode_invalid() {
+    let mut encoded = [0; UsizeAs::<u16>::SIZE];
+    rawcode::to_slice(&UsizeAs::<u16>::new(65_536), &mut encoded).expect_err("Unexpected success");
+
+    let mut encoded = [0; IsizeAs::<i8>::SIZE];
+    rawcode::to_slice(&IsizeAs::<i8>::new(-129), &mut encoded).expect_err("Unexpected success");
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [(b"\x00\x00\x00\x00", 0usize), (b"\xff\x00\x00\x00", 255), (b"\xff\xff\xff\xff", 0xffff_ffff)];
+    for (encoded, expected) in tests {
+        let decoded: UsizeAs<u32> = rawcode::from_slice(encoded)?;
+        assert_eq!(decoded.get(), expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [b"".as_slice(), b"\x01".as_slice(), b"\xFF\xFF\xFF\xFF\xFF".as_slice()];
+    for invalid in tests {
+        let result: Result<UsizeAs<u32>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+
+    // Values that exceed the host's `usize`
+    let overflow = [0xff; 16];
+    let result: Result<UsizeAs<u128>, Error> = rawcode::from_slice(&overflow);
+    result.expect_err("Unexpected success");
+
+    // Negative values cannot be represented as `usize`
+    let negative = [0xff; 4];
+    let result: Result<UsizeAs<i32>, Error> = rawcode::from_slice(&negative);
+    result.expect_err("Unexpected success");
+    Ok(())
+}
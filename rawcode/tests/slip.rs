@@ -0,0 +1,78 @@
+use rawcode::{
+    error::{Error, ErrorKind},
+    framing::{ByteStuffing, Slip},
+    RawcodeConstSize,
+};
+
+/// Encodes `data` into a SLIP frame
+fn encode(data: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut buf = vec![0; Slip::max_encoded_len(data.len())];
+    let len = Slip::encode(data, &mut buf)?;
+    buf.truncate(len);
+    Ok(buf)
+}
+
+/// Tests encoding of known vectors
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    assert_eq!(encode(b"")?, b"\xC0\xC0");
+    assert_eq!(encode(b"\x01\x02")?, b"\xC0\x01\x02\xC0");
+    assert_eq!(encode(b"\xC0\x01\xDB")?, b"\xC0\xDB\xDC\x01\xDB\xDD\xC0");
+
+    // Buffers smaller than the worst case are rejected
+    let error = Slip::encode(b"\x01", &mut [0; 3]).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::BufferTooSmall { expected: 4, actual: 3 });
+    Ok(())
+}
+
+/// Tests encoding of rawcode values directly into frames
+#[test]
+fn frame_valid() -> Result<(), Error> {
+    // A value that consists of bytes to escape only is the worst case
+    let value: [u8; 64] = core::array::from_fn(|index| [Slip::END, Slip::ESC][index % 2]);
+    let mut buf = [0; Slip::max_encoded_len(<[u8; 64]>::SIZE)];
+    let len = Slip::to_frame(&value, &mut buf)?;
+    assert_eq!(len, buf.len());
+    assert_eq!(buf[..len], encode(&value)?);
+    assert_eq!(Slip::from_frame::<[u8; 64]>(&mut buf[..len])?, value);
+
+    // A mixed value
+    let value = [0xC0DB_0001u32, 0xDBDB_C0C0, 7];
+    let mut buf = [0xAA; Slip::max_encoded_len(<[u32; 3]>::SIZE) + 4];
+    let len = Slip::to_frame(&value, &mut buf)?;
+    assert_eq!(Slip::from_frame::<[u32; 3]>(&mut buf[..len])?, value);
+    Ok(())
+}
+
+/// Tests decoding of known vectors
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let mut buf = [0; 3];
+    let len = Slip::decode(b"\xC0\xDB\xDC\x01\xDB\xDD\xC0", &mut buf)?;
+    assert_eq!(buf[..len], *b"\xC0\x01\xDB");
+
+    // The delimiters are optional
+    let len = Slip::decode(b"\x01\x02", &mut buf)?;
+    assert_eq!(buf[..len], *b"\x01\x02");
+
+    // Empty frames decode to empty data
+    assert_eq!(Slip::decode(b"", &mut buf)?, 0);
+    assert_eq!(Slip::decode(b"\xC0\xC0", &mut buf)?, 0);
+
+    let mut frame = *b"\x01\xDB\xDC\xC0";
+    assert_eq!(Slip::decode_in_place(&mut frame)?, b"\x01\xC0");
+    Ok(())
+}
+
+/// Tests decoding of invalid frames
+#[test]
+fn decode_invalid() {
+    let invalid: [&[u8]; 3] = [b"\xC0\x01\xC0\x02\xC0", b"\xC0\xDB\x01\xC0", b"\xC0\x01\xDB\xC0"];
+    for frame in invalid {
+        let error = Slip::decode(frame, &mut [0; 8]).expect_err("Unexpected success");
+        assert_eq!(error.kind(), ErrorKind::InvalidStuffing);
+    }
+
+    let error = Slip::decode(b"\xC0\x01\x02\x03\xC0", &mut [0; 2]).expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::BufferTooSmall { expected: 3, actual: 2 });
+}
@@ -0,0 +1,72 @@
+use rawcode::{
+    error::{Error, ErrorKind},
+    StreamDecoder,
+};
+
+/// Tests decoding of records that are split across chunks
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let mut decoder = StreamDecoder::<u32, 4>::new();
+
+    // A chunk with one complete record and the start of the next one
+    let records: Vec<_> = decoder.push(b"\x01\x00\x00\x00\x02\x00").collect::<Result<_, _>>()?;
+    assert_eq!(records, [1]);
+    assert_eq!(decoder.pending(), 2);
+
+    // A chunk that does not complete the record
+    assert!(decoder.push(b"\x00").next().is_none());
+    assert_eq!(decoder.pending(), 3);
+
+    // A chunk that completes the record and contains multiple further records
+    let records: Vec<_> = decoder.push(b"\x00\x03\x00\x00\x00\x04\x00\x00\x00").collect::<Result<_, _>>()?;
+    assert_eq!(records, [2, 3, 4]);
+    assert_eq!((decoder.pending(), decoder.records()), (0, 4));
+
+    // Discard an incomplete record
+    assert!(decoder.push(b"\xff\xff").next().is_none());
+    decoder.reset();
+    let records: Vec<_> = decoder.push(b"\x05\x00\x00\x00").collect::<Result<_, _>>()?;
+    assert_eq!(records, [5]);
+    Ok(())
+}
+
+/// Tests that invalid records are reported without stopping the decoding
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let mut decoder = StreamDecoder::<[bool; 2], 2>::new();
+    let mut records = decoder.push(b"\xff\x00\x01");
+
+    // The first record is valid
+    assert_eq!(records.next().transpose()?, Some([true, false]));
+
+    // The second record fails to decode
+    assert!(records.next().is_none());
+    drop(records);
+    assert_eq!(decoder.pending(), 1);
+    let error = decoder.push(b"\x00").next().expect("Missing record").expect_err("Unexpected success");
+    assert_eq!(error.kind(), ErrorKind::InvalidBool);
+    assert_eq!((error.path(), error.offset()), ("[1][0]", 2));
+
+    // The following records are still aligned
+    let records: Vec<_> = decoder.push(b"\x00\xff\x00").collect::<Result<_, _>>()?;
+    assert_eq!(records, [[false, true]]);
+    assert_eq!((decoder.pending(), decoder.skipped()), (1, 0));
+    Ok(())
+}
+
+/// Tests that dropping the records iterator keeps the stream aligned and counts the skipped records
+#[test]
+fn drop_records() -> Result<(), Error> {
+    let mut decoder = StreamDecoder::<u16, 2>::new();
+
+    // Drop the iterator after the first record
+    let mut records = decoder.push(b"\x01\x00\x02\x00\x03");
+    assert_eq!(records.next().transpose()?, Some(1));
+    drop(records);
+    assert_eq!((decoder.pending(), decoder.records(), decoder.skipped()), (1, 2, 1));
+
+    // The following records are still aligned
+    let records: Vec<_> = decoder.push(b"\x00\x04\x00").collect::<Result<_, _>>()?;
+    assert_eq!(records, [3, 4]);
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use core::time::Duration;
+use rawcode::{error::Error, RawcodeConstSize};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [
+        (Duration::ZERO, b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"),
+        (Duration::new(258, 999_999_999), b"\x02\x01\x00\x00\x00\x00\x00\x00\xff\xc9\x9a\x3b"),
+    ];
+
+    for (value, expected) in tests {
+        let mut encoded = [0; Duration::SIZE];
+        rawcode::to_slice(&value, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [
+        (b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00", Duration::ZERO),
+        (b"\x02\x01\x00\x00\x00\x00\x00\x00\xff\xc9\x9a\x3b", Duration::new(258, 999_999_999)),
+    ];
+
+    for (encoded, expected) in tests {
+        let decoded: Duration = rawcode::from_slice(encoded)?;
+        assert_eq!(decoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [
+        b"".as_slice(),
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00".as_slice(),
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00".as_slice(),
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\xca\x9a\x3b".as_slice(),
+    ];
+
+    for invalid in tests {
+        let result: Result<Duration, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
+
+/// Tests the `SystemTime` wrappers
+#[test]
+#[cfg(feature = "std")]
+fn system_time() -> Result<(), Error> {
+    use rawcode::{UnixMillis, UnixNanos, UnixSeconds};
+    use std::time::SystemTime;
+
+    // Post-epoch times are rounded down
+    let time = SystemTime::UNIX_EPOCH + Duration::new(1_700_000_000, 999_999_999);
+    let mut encoded = [0; UnixSeconds::<u32>::SIZE];
+    rawcode::to_slice(&UnixSeconds::<u32>::new(time), &mut encoded)?;
+    assert_eq!(encoded, 1_700_000_000u32.to_le_bytes());
+
+    let decoded: UnixNanos<u64> = rawcode::from_slice(&1_700_000_000_999_999_999u64.to_le_bytes())?;
+    assert_eq!(decoded.get(), time);
+
+    // Pre-epoch times are rounded towards negative infinity and require a signed wire type
+    let time = SystemTime::UNIX_EPOCH - Duration::from_micros(1_500);
+    let mut encoded = [0; UnixMillis::<i64>::SIZE];
+    rawcode::to_slice(&UnixMillis::<i64>::new(time), &mut encoded)?;
+    assert_eq!(encoded, (-2i64).to_le_bytes());
+
+    let decoded: UnixMillis<i64> = rawcode::from_slice(&encoded)?;
+    assert_eq!(decoded.get(), SystemTime::UNIX_EPOCH - Duration::from_millis(2));
+
+    let mut encoded = [0; UnixMillis::<u64>::SIZE];
+    rawcode::to_slice(&UnixMillis::<u64>::new(time), &mut encoded).expect_err("Unexpected success");
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use rawcode::{error::Error, PaddedUtf16Array, RawcodeConstSize, Utf16Array};
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let tests = [("ab", b"a\x00b\x00"), ("ä\0", b"\xe4\x00\x00\x00"), ("😀", b"\x3d\xd8\x00\xde")];
+    for (value, expected) in tests {
+        let mut encoded = [0; Utf16Array::<2>::SIZE];
+        rawcode::to_slice(&Utf16Array::<2>::try_from(value)?, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+
+    let tests = [("", b"\x00\x00\x00\x00"), ("a", b"a\x00\x00\x00"), ("😀", b"\x3d\xd8\x00\xde")];
+    for (value, expected) in tests {
+        let mut encoded = [0; PaddedUtf16Array::<2>::SIZE];
+        rawcode::to_slice(&PaddedUtf16Array::<2>::try_from(value)?, &mut encoded)?;
+        assert_eq!(&encoded, expected);
+    }
+    Ok(())
+}
+
+/// Tests construction of invalid values
+#[test]
+fn construct_invalid() {
+    let tests = ["a", "abc", "ä😀"];
+    for invalid in tests {
+        Utf16Array::<2>::try_from(invalid).expect_err("Unexpected success");
+    }
+
+    let tests = ["abc", "a\0"];
+    for invalid in tests {
+        PaddedUtf16Array::<2>::try_from(invalid).expect_err("Unexpected success");
+    }
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let tests = [(b"a\x00b\x00", "ab"), (b"\x3d\xd8\x00\xde", "😀"), (b"\x00\x00a\x00", "\0a")];
+    for (encoded, expected) in tests {
+        let decoded: Utf16Array<2> = rawcode::from_slice(encoded)?;
+        assert!(decoded.chars().eq(expected.chars()));
+    }
+
+    let tests = [(b"\x00\x00\x00\x00", ""), (b"a\x00\x00\x00", "a"), (b"\x3d\xd8\x00\xde", "😀")];
+    for (encoded, expected) in tests {
+        let decoded: PaddedUtf16Array<2> = rawcode::from_slice(encoded)?;
+        assert!(decoded.chars().eq(expected.chars()));
+        #[cfg(feature = "alloc")]
+        assert_eq!(String::from(decoded), expected);
+    }
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [
+        b"".as_slice(),
+        b"a\x00".as_slice(),
+        b"a\x00b\x00c".as_slice(),
+        b"\x3d\xd8a\x00".as_slice(),
+        b"\x00\xdea\x00".as_slice(),
+        b"a\x00\x3d\xd8".as_slice(),
+    ];
+    for invalid in tests {
+        let result: Result<Utf16Array<2>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+
+    let tests = [b"\x00\x00a\x00".as_slice(), b"\x3d\xd8\x00\x00".as_slice()];
+    for invalid in tests {
+        let result: Result<PaddedUtf16Array<2>, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
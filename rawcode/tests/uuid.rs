@@ -0,0 +1,62 @@
+use rawcode::{error::Error, Guid, RawcodeConstSize, Uuid16, UuidVariant};
+
+/// The test UUID
+const UUID: &str = "00112233-4455-6677-8899-aabbccddeeff";
+
+/// Tests encoding of valid value
+#[test]
+fn encode_valid() -> Result<(), Error> {
+    let mut encoded = [0; Uuid16::<rawcode::Rfc4122>::SIZE];
+    rawcode::to_slice(&UUID.parse::<Uuid16>()?, &mut encoded)?;
+    assert_eq!(&encoded, b"\x00\x11\x22\x33\x44\x55\x66\x77\x88\x99\xaa\xbb\xcc\xdd\xee\xff");
+
+    let mut encoded = [0; Guid::SIZE];
+    rawcode::to_slice(&UUID.parse::<Guid>()?, &mut encoded)?;
+    assert_eq!(&encoded, b"\x33\x22\x11\x00\x55\x44\x77\x66\x88\x99\xaa\xbb\xcc\xdd\xee\xff");
+    Ok(())
+}
+
+/// Tests decoding of valid values
+#[test]
+fn decode_valid() -> Result<(), Error> {
+    let decoded: Uuid16 = rawcode::from_slice(b"\x00\x11\x22\x33\x44\x55\x66\x77\x88\x99\xaa\xbb\xcc\xdd\xee\xff")?;
+    assert_eq!(decoded.to_string(), UUID);
+
+    let decoded: Guid = rawcode::from_slice(b"\x33\x22\x11\x00\x55\x44\x77\x66\x88\x99\xaa\xbb\xcc\xdd\xee\xff")?;
+    assert_eq!(decoded.to_string(), UUID);
+    assert_eq!(decoded.with_layout(), UUID.parse::<Uuid16>()?);
+    Ok(())
+}
+
+/// Tests decoding of invalid values
+#[test]
+fn decode_invalid() -> Result<(), Error> {
+    let tests = [b"".as_slice(), [0; 15].as_slice(), [0; 17].as_slice()];
+
+    for invalid in tests {
+        let result: Result<Uuid16, Error> = rawcode::from_slice(invalid);
+        result.expect_err("Unexpected success");
+    }
+    Ok(())
+}
+
+/// Tests parsing and inspection
+#[test]
+fn parse() -> Result<(), Error> {
+    let uuid: Uuid16 = "6BA7B810-9DAD-11D1-80B4-00C04FD430C8".parse()?;
+    assert_eq!(uuid.to_string(), "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    assert_eq!(uuid.version(), 1);
+    assert_eq!(uuid.variant(), UuidVariant::Rfc4122);
+    assert_eq!(Uuid16::<rawcode::Rfc4122>::NIL.variant(), UuidVariant::Ncs);
+
+    let tests = [
+        "",
+        "6ba7b810-9dad-11d1-80b4-00c04fd430c",
+        "6ba7b810x9dad-11d1-80b4-00c04fd430c8",
+        "6ba7b810-9dad-11d1-80b4-00c04fd430cg",
+    ];
+    for invalid in tests {
+        invalid.parse::<Uuid16>().expect_err("Unexpected success");
+    }
+    Ok(())
+}